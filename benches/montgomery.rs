@@ -0,0 +1,64 @@
+//! `ModInt`と`MontgomeryModInt`で長い列の総積と要素ごとの積の時間を比べる
+//!
+//! 要素ごとの積では約2倍速いが, 順に掛ける総積は依存関係で律速されるため1割程度しか速くならない
+//!
+//! `cargo bench --bench montgomery`で実行する
+
+use my_prolib::modint::{ModInt, MontgomeryModInt};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const N: u32 = 998244353;
+const LEN: usize = 1 << 20;
+const ROUNDS: usize = 50;
+
+/// 総積と要素ごとの積にかかった時間を返す
+fn measure<T: Copy + std::ops::Mul<Output = T> + std::iter::Product>(
+    v: &[T],
+) -> (Duration, Duration) {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(black_box(v).iter().copied().product::<T>());
+    }
+    let product = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let w = black_box(v);
+        black_box(
+            w.iter()
+                .zip(w.iter().rev())
+                .map(|(&a, &b)| a * b)
+                .collect::<Vec<_>>(),
+        );
+    }
+    (product, start.elapsed())
+}
+
+fn main() {
+    let mut x = 88172645463325252_u64;
+    let v: Vec<ModInt<N>> = (0..LEN)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            ModInt::new(x as u32)
+        })
+        .collect();
+    let w: Vec<MontgomeryModInt<N>> = v.iter().map(|&a| a.into()).collect();
+    assert_eq!(
+        ModInt::from(w.iter().copied().product::<MontgomeryModInt<N>>()),
+        v.iter().copied().product::<ModInt<N>>()
+    );
+
+    let (a, c) = measure(&v);
+    let (b, d) = measure(&w);
+    println!(
+        "product:   ModInt {a:?}, MontgomeryModInt {b:?} ({:.2}x)",
+        a.as_secs_f64() / b.as_secs_f64()
+    );
+    println!(
+        "pointwise: ModInt {c:?}, MontgomeryModInt {d:?} ({:.2}x)",
+        c.as_secs_f64() / d.as_secs_f64()
+    );
+}
@@ -29,6 +29,103 @@ pub trait Divisibility: Magma {
     fn div(a: &Self::T, b: &Self::T) -> Self::T;
 }
 
+/// `a`を`n`個並べた総積を返す
+///
+/// # Complexity
+///
+/// * *Θ*(log *n*)
+#[must_use]
+pub fn pow<M: Associative + Identity>(a: &M::T, n: u64) -> M::T {
+    let mut r = M::e();
+    for i in (0..(u64::BITS - n.leading_zeros())).rev() {
+        r = M::op(&r, &r);
+        if (n >> i) & 1 == 1 {
+            r = M::op(&r, a);
+        }
+    }
+    r
+}
+
+/// `Self`の要素を写像として`M`の要素に作用させる. `op(f, g)`は`g`を作用させた後に`f`を作用させる写像である
+///
+/// 写像`f`, `g`と長さ`n`, `m`の区間の総積`a`, `b`について
+///
+/// * `act(op(f, g), a, n)` = `act(f, act(g, a, n), n)`
+/// * `act(e, a, n)` = `a`
+/// * `act(f, M::op(a, b), n + m)` = `M::op(act(f, a, n), act(f, b, m))`
+pub trait Action<M: Magma>: Associative + Identity {
+    #[must_use]
+    fn act(f: &Self::T, a: &M::T, len: usize) -> M::T;
+}
+
+/// `Add`を加法, `Mul`を乗法とする半環
+///
+/// 要素`a`, `b`, `c`について
+///
+/// * `Mul::op(a, Add::op(b, c))` = `Add::op(Mul::op(a, b), Mul::op(a, c))`
+/// * `Mul::op(Add::op(a, b), c)` = `Add::op(Mul::op(a, c), Mul::op(b, c))`
+/// * `Mul::op(Add::e(), a)` = `Mul::op(a, Add::e())` = `Add::e()`
+///
+/// 二つの構造の組`(Add, Mul)`がこれを実装する
+pub trait Semiring {
+    type T;
+    type Add: Associative + Commutative + Identity<T = Self::T>;
+    type Mul: Associative + Identity<T = Self::T>;
+}
+impl<A, M> Semiring for (A, M)
+where
+    A: Associative + Commutative + Identity,
+    M: Associative + Identity<T = A::T>,
+{
+    type T = A::T;
+    type Add = A;
+    type Mul = M;
+}
+
+/// モノイドを定義する
+///
+/// 型, 単位元, 演算に続けて`commutative`, `idempotent`, `inverse(|a| ...)`を必要に応じて指定する
+///
+/// ```
+/// my_prolib::monoid!(pub XorU64: u64, 0, |a, b| a ^ b, commutative, inverse(|a| *a));
+/// ```
+#[macro_export]
+macro_rules! monoid {
+    (@ $name: ident, $t: ty, commutative) => {
+        impl $crate::algebra::Commutative for $name {}
+    };
+    (@ $name: ident, $t: ty, idempotent) => {
+        impl $crate::algebra::Idempotent for $name {}
+    };
+    (@ $name: ident, $t: ty, inverse(|$a: ident| $rev: expr)) => {
+        impl $crate::algebra::Inverse for $name {
+            #[inline]
+            fn rev($a: &$t) -> $t {
+                $rev
+            }
+        }
+    };
+    ($v: vis $name: ident: $t: ty, $e: expr, |$a: ident, $b: ident| $op: expr $(, $f: ident $(($($g: tt)*))?)* $(,)?) => {
+        $v struct $name;
+        impl $crate::algebra::Magma for $name {
+            type T = $t;
+
+            #[inline]
+            fn op($a: &$t, $b: &$t) -> $t {
+                $op
+            }
+        }
+        impl $crate::algebra::Associative for $name {}
+        impl $crate::algebra::Identity for $name {
+            #[inline]
+            fn e() -> $t {
+                $e
+            }
+        }
+        $($crate::monoid!(@ $name, $t, $f $(($($g)*))?);)*
+    };
+}
+
 mod add;
 pub use add::Add;
 
@@ -37,3 +134,65 @@ pub use min::Min;
 
 mod max;
 pub use max::Max;
+
+mod product;
+pub use product::Product;
+
+mod mul;
+pub use mul::{CommutativeMul, Mul};
+
+mod xor;
+pub use xor::Xor;
+
+mod bit_and;
+pub use bit_and::BitAnd;
+
+mod bit_or;
+pub use bit_or::BitOr;
+
+mod gcd;
+pub use gcd::Gcd;
+
+mod lcm;
+pub use lcm::Lcm;
+
+mod affine;
+pub use affine::Affine;
+
+mod rev;
+pub use rev::Rev;
+
+mod opt;
+pub use opt::Opt;
+
+mod index;
+pub use index::{MaxIndex, MinIndex};
+
+mod max_subarray;
+pub use max_subarray::{MaxSubarray, Subarray};
+
+pub mod laws;
+
+#[cfg(test)]
+mod tests {
+    use crate::segment_tree::SegmentTree;
+    use crate::sparse_table::SparseTable;
+
+    crate::monoid!(Concat: String, String::new(), |a, b| format!("{a}{b}"));
+    crate::monoid!(Sum: i64, 0, |a, b| a + b, commutative, inverse(|a| -a));
+    crate::monoid!(Or: u32, 0, |a, b| a | b, commutative, idempotent);
+
+    #[test]
+    fn monoid_macro_works() {
+        let seg = SegmentTree::<Concat>::from(["ab", "ra", "ca", "da", "bra"].map(String::from));
+        assert_eq!(seg.prod(1..4), "racada");
+        assert_eq!(seg.prod(2..2), "");
+
+        let seg = SegmentTree::<Sum>::from([3, 1, 4, 1, 5]);
+        assert_eq!(seg.prod(1..), 11);
+        assert_eq!(<Sum as super::Inverse>::rev(&7), -7);
+
+        let st = SparseTable::<Or>::from([1, 2, 4, 8]);
+        assert_eq!(st.prod(1..3), Some(6));
+    }
+}
@@ -1,8 +1,36 @@
-use super::{Associative, Commutative, Divisibility, Identity, Inverse, Magma};
+use super::{Action, Associative, Commutative, Divisibility, Identity, Inverse, Magma, Max, Min};
 
 pub struct Add<T> {
     _p: std::marker::PhantomData<fn() -> T>,
 }
+impl<T> Add<T>
+where
+    Add<T>: Identity<T = T>,
+{
+    /// `a` + `f`·`n`を返す. `n`を`T`に変換できない場合に使う
+    ///
+    /// 途中の値は`a`と結果の間にあるか, 絶対値が|`f`·`n`| / 2以下なので, `a`と結果が`T`に収まれば溢れない
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(log *n*)
+    pub(super) fn scale_add(a: &T, f: &T, n: usize) -> T {
+        let mut r = Self::op(a, &Self::e());
+        if n & 1 == 1 {
+            r = Self::op(&r, f);
+        }
+        let mut h = Self::op(f, &Self::e());
+        for i in 1..(usize::BITS - n.leading_zeros()) {
+            if (n >> i) & 1 == 1 {
+                r = Self::op(&Self::op(&r, &h), &h);
+            }
+            if (n >> (i + 1)) != 0 {
+                h = Self::op(&h, &h);
+            }
+        }
+        r
+    }
+}
 impl<T> Magma for Add<T>
 where
     for<'a> &'a T: std::ops::Add<Output = T>,
@@ -45,3 +73,67 @@ where
         a - b
     }
 }
+impl<T> Action<Add<T>> for Add<T>
+where
+    T: num::FromPrimitive,
+    Add<T>: Identity<T = T>,
+    for<'a> &'a T: std::ops::Mul<Output = T>,
+{
+    #[inline]
+    fn act(f: &T, a: &T, len: usize) -> T {
+        match T::from_usize(len) {
+            Some(n) => Self::op(a, &(f * &n)),
+            None => Self::scale_add(a, f, len),
+        }
+    }
+}
+impl<T> Action<Min<T>> for Add<T>
+where
+    Add<T>: Identity<T = T>,
+    Min<T>: Magma<T = T>,
+{
+    #[inline]
+    fn act(f: &T, a: &T, _: usize) -> T {
+        Self::op(a, f)
+    }
+}
+impl<T> Action<Max<T>> for Add<T>
+where
+    Add<T>: Identity<T = T>,
+    Max<T>: Magma<T = T>,
+{
+    #[inline]
+    fn act(f: &T, a: &T, _: usize) -> T {
+        Self::op(a, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy_segment_tree::LazySegmentTree;
+
+    #[test]
+    fn scale_add_works() {
+        for a in i8::MIN..=i8::MAX {
+            for f in [-128, -3, -1, 0, 1, 2, 127] {
+                for n in 0..=300 {
+                    let r = a as i64 + f as i64 * n as i64;
+                    if let Ok(r) = i8::try_from(r) {
+                        assert_eq!(Add::<i8>::scale_add(&a, &f, n), r);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_add_small_type() {
+        let mut seg = LazySegmentTree::<Add<i8>, Add<i8>>::from(vec![-1; 128]);
+        seg.apply(.., 1);
+        assert_eq!(seg.all_prod(), Some(&0));
+        seg.apply(..127, 1);
+        assert_eq!(seg.prod(..), 127);
+        assert_eq!(seg.prod(1..), 126);
+    }
+}
@@ -0,0 +1,131 @@
+use super::{Action, Add, Associative, Identity, Inverse, Magma};
+
+/// 一次関数`x` ↦ `a`·`x` + `b`を`(a, b)`で表し, 合成を演算とする
+///
+/// `op(f, g)`は`g`を適用した後に`f`を適用する関数である
+pub struct Affine<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+impl<T> Affine<T>
+where
+    for<'a> &'a T: std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    /// `f(x)`を計算する
+    #[inline]
+    #[must_use]
+    pub fn eval(f: &(T, T), x: &T) -> T {
+        &(&f.0 * x) + &f.1
+    }
+}
+impl<T> Magma for Affine<T>
+where
+    for<'a> &'a T: std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    type T = (T, T);
+
+    #[inline]
+    fn op(f: &(T, T), g: &(T, T)) -> (T, T) {
+        (&f.0 * &g.0, Self::eval(f, &g.1))
+    }
+}
+impl<T> Associative for Affine<T> where Affine<T>: Magma<T = (T, T)> {}
+impl<T: num::Zero + num::One> Identity for Affine<T>
+where
+    Affine<T>: Magma<T = (T, T)>,
+{
+    #[inline]
+    fn e() -> (T, T) {
+        (T::one(), T::zero())
+    }
+}
+impl<T> Inverse for Affine<T>
+where
+    Affine<T>: Identity<T = (T, T)>,
+    for<'a> &'a T:
+        std::ops::Mul<Output = T> + std::ops::Neg<Output = T> + num::traits::Inv<Output = T>,
+{
+    #[inline]
+    fn rev(f: &(T, T)) -> (T, T) {
+        let a = num::traits::Inv::inv(&f.0);
+        let b = -&(&f.1 * &a);
+        (a, b)
+    }
+}
+impl<T> Action<Add<T>> for Affine<T>
+where
+    T: num::FromPrimitive,
+    Affine<T>: Identity<T = (T, T)>,
+    Add<T>: Identity<T = T>,
+    for<'a> &'a T: std::ops::Mul<Output = T>,
+{
+    #[inline]
+    fn act(f: &(T, T), a: &T, len: usize) -> T {
+        match T::from_usize(len) {
+            Some(n) => Add::<T>::op(&(&f.0 * a), &(&f.1 * &n)),
+            None => Add::<T>::scale_add(&(&f.0 * a), &f.1, len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy_segment_tree::LazySegmentTree;
+    use crate::modint::ModInt;
+
+    type Mint = ModInt<998244353>;
+
+    #[test]
+    fn affine_works() {
+        let f = (Mint::new(2), Mint::new(3));
+        let g = (Mint::new(5), Mint::new(7));
+        let x = Mint::new(11);
+
+        assert_eq!(
+            Affine::<Mint>::eval(&Affine::<Mint>::op(&f, &g), &x),
+            Affine::<Mint>::eval(&f, &Affine::<Mint>::eval(&g, &x))
+        );
+        assert_eq!(
+            Affine::<Mint>::op(&f, &Affine::<Mint>::rev(&f)),
+            Affine::<Mint>::e()
+        );
+        assert_eq!(
+            Affine::<Mint>::op(&Affine::<Mint>::rev(&g), &g),
+            Affine::<Mint>::e()
+        );
+    }
+
+    #[test]
+    fn small_type() {
+        let mut seg = LazySegmentTree::<Add<i8>, Affine<i8>>::from(vec![-1; 128]);
+        seg.apply(.., (1, 1));
+        assert_eq!(seg.prod(..), 0);
+        seg.apply(..127, (-1, 1));
+        assert_eq!(seg.prod(..), 127);
+    }
+
+    #[test]
+    fn range_affine_range_sum() {
+        let mut v = [1, 2, 3, 4, 5].map(Mint::new);
+        let mut seg = LazySegmentTree::<Add<Mint>, Affine<Mint>>::from(v);
+
+        for (l, r, a, b) in [
+            (0, 3, 2, 1),
+            (1, 5, 998244352, 4),
+            (2, 4, 100, 0),
+            (0, 5, 1, 7),
+        ] {
+            let f = (Mint::new(a), Mint::new(b));
+            seg.apply(l..r, f);
+            v[l..r]
+                .iter_mut()
+                .for_each(|x| *x = Affine::<Mint>::eval(&f, x));
+
+            for l in 0..=v.len() {
+                for r in l..=v.len() {
+                    assert_eq!(seg.prod(l..r), v[l..r].iter().copied().sum());
+                }
+            }
+        }
+    }
+}
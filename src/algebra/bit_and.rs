@@ -0,0 +1,43 @@
+use super::{Associative, Commutative, Idempotent, Identity, Magma};
+
+pub struct BitAnd<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+impl<T> Magma for BitAnd<T>
+where
+    for<'a> &'a T: std::ops::BitAnd<Output = T>,
+{
+    type T = T;
+
+    #[inline]
+    fn op(a: &Self::T, b: &Self::T) -> Self::T {
+        a & b
+    }
+}
+impl<T> Associative for BitAnd<T> where BitAnd<T>: Magma<T = T> {}
+impl<T> Commutative for BitAnd<T> where BitAnd<T>: Magma<T = T> {}
+impl<T> Idempotent for BitAnd<T> where BitAnd<T>: Magma<T = T> {}
+impl<T: Default + std::ops::Not<Output = T>> Identity for BitAnd<T>
+where
+    BitAnd<T>: Magma<T = T>,
+{
+    #[inline]
+    fn e() -> Self::T {
+        !Self::T::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::segment_tree::SegmentTree;
+    use crate::sparse_table::SparseTable;
+
+    #[test]
+    fn bit_and_works() {
+        let and = SparseTable::<BitAnd<u8>>::from([0b1100_u8, 0b1010, 0b0110]);
+
+        assert_eq!(and.prod(..2), Some(0b1000));
+        assert!(SegmentTree::<BitAnd<bool>>::from([]).prod(..));
+    }
+}
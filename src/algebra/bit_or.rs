@@ -0,0 +1,41 @@
+use super::{Associative, Commutative, Idempotent, Identity, Magma};
+
+pub struct BitOr<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+impl<T> Magma for BitOr<T>
+where
+    for<'a> &'a T: std::ops::BitOr<Output = T>,
+{
+    type T = T;
+
+    #[inline]
+    fn op(a: &Self::T, b: &Self::T) -> Self::T {
+        a | b
+    }
+}
+impl<T> Associative for BitOr<T> where BitOr<T>: Magma<T = T> {}
+impl<T> Commutative for BitOr<T> where BitOr<T>: Magma<T = T> {}
+impl<T> Idempotent for BitOr<T> where BitOr<T>: Magma<T = T> {}
+impl<T: Default> Identity for BitOr<T>
+where
+    BitOr<T>: Magma<T = T>,
+{
+    #[inline]
+    fn e() -> Self::T {
+        Self::T::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sparse_table::SparseTable;
+
+    #[test]
+    fn bit_or_works() {
+        let or = SparseTable::<BitOr<u8>>::from([0b1100_u8, 0b1010, 0b0110]);
+
+        assert_eq!(or.prod(1..), Some(0b1110));
+    }
+}
@@ -0,0 +1,47 @@
+use super::{Associative, Commutative, Idempotent, Identity, Magma};
+
+/// 最大公約数を演算とする
+///
+/// 負の`a`では`op(a, a)` = `op(a, 0)` = |`a`|となるため, `Idempotent`と`Identity`は符号なし整数に限る
+pub struct Gcd<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+impl<T> Magma for Gcd<T>
+where
+    T: num::Integer,
+{
+    type T = T;
+
+    #[inline]
+    fn op(a: &T, b: &T) -> T {
+        a.gcd(b)
+    }
+}
+impl<T> Associative for Gcd<T> where Gcd<T>: Magma<T = T> {}
+impl<T> Commutative for Gcd<T> where Gcd<T>: Magma<T = T> {}
+impl<T: num::Unsigned> Idempotent for Gcd<T> where Gcd<T>: Magma<T = T> {}
+impl<T> Identity for Gcd<T>
+where
+    T: num::Integer + num::Unsigned,
+{
+    #[inline]
+    fn e() -> T {
+        T::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sparse_table::SparseTable;
+
+    #[test]
+    fn gcd_works() {
+        let gcd = SparseTable::<Gcd<u64>>::from([12_u64, 18, 30, 42, 7]);
+
+        assert_eq!(gcd.prod(..4), Some(6));
+        assert_eq!(gcd.prod(..), Some(1));
+        assert_eq!(Gcd::<i64>::op(&-6, &-6), 6);
+        assert_eq!(Gcd::<i64>::op(&-6, &0), 6);
+    }
+}
@@ -0,0 +1,100 @@
+use super::{Associative, Commutative, Idempotent, Identity, Magma};
+
+macro_rules! ui {
+    ($($t: ident, $cmp: tt, $e: ident, $doc: literal),*) => ($(
+        #[doc = concat!("値と添字の組`(x, i)`について", $doc, "とその位置を求める")]
+        ///
+        #[doc = concat!($doc, "が複数ある場合は, `RIGHT`が`false`なら最も左, `true`なら最も右の位置を選ぶ")]
+        pub struct $t<T, const RIGHT: bool = false> {
+            _p: std::marker::PhantomData<fn() -> T>,
+        }
+        impl<T, const RIGHT: bool> Magma for $t<T, RIGHT>
+        where
+            T: Clone + Ord,
+        {
+            type T = (T, usize);
+
+            #[inline]
+            fn op(a: &(T, usize), b: &(T, usize)) -> (T, usize) {
+                if a.0 $cmp b.0 || (a.0 == b.0 && (a.1 < b.1) != RIGHT) {
+                    a.clone()
+                } else {
+                    b.clone()
+                }
+            }
+        }
+        impl<T, const RIGHT: bool> Associative for $t<T, RIGHT> where $t<T, RIGHT>: Magma<T = (T, usize)> {}
+        impl<T, const RIGHT: bool> Commutative for $t<T, RIGHT> where $t<T, RIGHT>: Magma<T = (T, usize)> {}
+        impl<T, const RIGHT: bool> Idempotent for $t<T, RIGHT> where $t<T, RIGHT>: Magma<T = (T, usize)> {}
+        impl<T, const RIGHT: bool> Identity for $t<T, RIGHT>
+        where
+            T: num::Bounded,
+            $t<T, RIGHT>: Magma<T = (T, usize)>,
+        {
+            #[inline]
+            fn e() -> (T, usize) {
+                (T::$e(), if RIGHT { 0 } else { usize::MAX })
+            }
+        }
+    )*);
+}
+
+ui! {
+    MinIndex, <, max_value, "最小値",
+    MaxIndex, >, min_value, "最大値"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::laws;
+    use super::*;
+    use crate::segment_tree::SegmentTree;
+
+    const V: [i32; 12] = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 9];
+
+    fn laws_hold<M>()
+    where
+        M: Associative + Commutative + Idempotent + Identity<T = (i32, usize)>,
+    {
+        let mut x = 1_u64;
+        let mut gen = move || {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            ((x % 5) as i32, (x >> 32) as usize % 5)
+        };
+
+        assert_eq!(laws::associative::<M>(&mut gen, 1000), Ok(()));
+        assert_eq!(laws::commutative::<M>(&mut gen, 1000), Ok(()));
+        assert_eq!(laws::idempotent::<M>(&mut gen, 1000), Ok(()));
+        assert_eq!(laws::identity::<M>(&mut gen, 1000), Ok(()));
+    }
+
+    #[test]
+    fn min_index_works() {
+        let seg = SegmentTree::<MinIndex<i32>>::from_values_indexed(V);
+        assert_eq!(seg.prod(..), (1, 1));
+        assert_eq!(seg.prod(4..), (2, 6));
+        assert_eq!(seg.prod(4..4), MinIndex::<i32>::e());
+        let seg = SegmentTree::<MinIndex<i32, true>>::from_values_indexed(V);
+        assert_eq!(seg.prod(..), (1, 3));
+        assert_eq!(seg.prod(..3), (1, 1));
+
+        laws_hold::<MinIndex<i32>>();
+        laws_hold::<MinIndex<i32, true>>();
+    }
+
+    #[test]
+    fn max_index_works() {
+        let seg = SegmentTree::<MaxIndex<i32>>::from_values_indexed(V);
+        assert_eq!(seg.prod(..), (9, 5));
+        assert_eq!(seg.prod(..5), (5, 4));
+        assert_eq!(seg.prod(4..4), MaxIndex::<i32>::e());
+        let seg = SegmentTree::<MaxIndex<i32, true>>::from_values_indexed(V);
+        assert_eq!(seg.prod(..), (9, 11));
+        assert_eq!(seg.prod(6..11), (6, 7));
+
+        laws_hold::<MaxIndex<i32>>();
+        laws_hold::<MaxIndex<i32, true>>();
+    }
+}
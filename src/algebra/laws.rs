@@ -0,0 +1,183 @@
+//! 代数的構造が主張する法則をランダムな値で検査する
+
+use super::{Associative, Commutative, Idempotent, Identity, Inverse};
+
+/// 法則が成り立たなかった反例
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation<T> {
+    /// 破れた法則の名前
+    pub law: &'static str,
+    /// 反例となる値
+    pub values: Vec<T>,
+}
+
+/// `op(op(a, b), c)` = `op(a, op(b, c))`を`trials`回検査する
+///
+/// # Complexity
+///
+/// * *Θ*(`trials`) 回の`op`と`gen`の呼び出し
+pub fn associative<M: Associative>(
+    mut gen: impl FnMut() -> M::T,
+    trials: usize,
+) -> Result<(), Violation<M::T>>
+where
+    M::T: PartialEq,
+{
+    for _ in 0..trials {
+        let (a, b, c) = (gen(), gen(), gen());
+        if M::op(&M::op(&a, &b), &c) != M::op(&a, &M::op(&b, &c)) {
+            return Err(Violation {
+                law: "associative",
+                values: vec![a, b, c],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// `op(a, b)` = `op(b, a)`を`trials`回検査する
+///
+/// # Complexity
+///
+/// * *Θ*(`trials`) 回の`op`と`gen`の呼び出し
+pub fn commutative<M: Commutative>(
+    mut gen: impl FnMut() -> M::T,
+    trials: usize,
+) -> Result<(), Violation<M::T>>
+where
+    M::T: PartialEq,
+{
+    for _ in 0..trials {
+        let (a, b) = (gen(), gen());
+        if M::op(&a, &b) != M::op(&b, &a) {
+            return Err(Violation {
+                law: "commutative",
+                values: vec![a, b],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// `op(a, a)` = `a`を`trials`回検査する
+///
+/// # Complexity
+///
+/// * *Θ*(`trials`) 回の`op`と`gen`の呼び出し
+pub fn idempotent<M: Idempotent>(
+    mut gen: impl FnMut() -> M::T,
+    trials: usize,
+) -> Result<(), Violation<M::T>>
+where
+    M::T: PartialEq,
+{
+    for _ in 0..trials {
+        let a = gen();
+        if M::op(&a, &a) != a {
+            return Err(Violation {
+                law: "idempotent",
+                values: vec![a],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// `op(e, a)` = `op(a, e)` = `a`を`trials`回検査する
+///
+/// # Complexity
+///
+/// * *Θ*(`trials`) 回の`op`と`gen`の呼び出し
+pub fn identity<M: Identity>(
+    mut gen: impl FnMut() -> M::T,
+    trials: usize,
+) -> Result<(), Violation<M::T>>
+where
+    M::T: PartialEq,
+{
+    let e = M::e();
+    for _ in 0..trials {
+        let a = gen();
+        if M::op(&e, &a) != a || M::op(&a, &e) != a {
+            return Err(Violation {
+                law: "identity",
+                values: vec![a],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// `op(rev(a), a)` = `op(a, rev(a))` = `e`を`trials`回検査する
+///
+/// # Complexity
+///
+/// * *Θ*(`trials`) 回の`op`と`gen`の呼び出し
+pub fn inverse<M: Inverse>(
+    mut gen: impl FnMut() -> M::T,
+    trials: usize,
+) -> Result<(), Violation<M::T>>
+where
+    M::T: PartialEq,
+{
+    let e = M::e();
+    for _ in 0..trials {
+        let a = gen();
+        let b = M::rev(&a);
+        if M::op(&b, &a) != e || M::op(&a, &b) != e {
+            return Err(Violation {
+                law: "inverse",
+                values: vec![a],
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Add, Max, Min};
+    use super::*;
+
+    fn xorshift(mut x: u64) -> impl FnMut() -> i64 {
+        move || {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            (x % 2_000_000_001) as i64 - 1_000_000_000
+        }
+    }
+
+    #[test]
+    fn laws_hold() {
+        const N: usize = 1000;
+
+        assert_eq!(associative::<Add<i64>>(xorshift(1), N), Ok(()));
+        assert_eq!(commutative::<Add<i64>>(xorshift(2), N), Ok(()));
+        assert_eq!(identity::<Add<i64>>(xorshift(3), N), Ok(()));
+        assert_eq!(inverse::<Add<i64>>(xorshift(4), N), Ok(()));
+
+        assert_eq!(associative::<Min<i64>>(xorshift(5), N), Ok(()));
+        assert_eq!(commutative::<Min<i64>>(xorshift(6), N), Ok(()));
+        assert_eq!(idempotent::<Min<i64>>(xorshift(7), N), Ok(()));
+        assert_eq!(identity::<Min<i64>>(xorshift(8), N), Ok(()));
+
+        assert_eq!(associative::<Max<i64>>(xorshift(9), N), Ok(()));
+        assert_eq!(commutative::<Max<i64>>(xorshift(10), N), Ok(()));
+        assert_eq!(idempotent::<Max<i64>>(xorshift(11), N), Ok(()));
+        assert_eq!(identity::<Max<i64>>(xorshift(12), N), Ok(()));
+    }
+
+    crate::monoid!(Sub: i64, 0, |a, b| a - b, commutative, idempotent);
+
+    #[test]
+    fn counterexample() {
+        assert_eq!(associative::<Sub>(|| 1, 1).unwrap_err().values, [1, 1, 1]);
+        assert_eq!(
+            commutative::<Sub>(xorshift(13), 100).unwrap_err().law,
+            "commutative"
+        );
+        assert!(idempotent::<Sub>(xorshift(14), 100).is_err());
+        assert_eq!(identity::<Sub>(|| 3, 1).unwrap_err().values, [3]);
+    }
+}
@@ -0,0 +1,46 @@
+use super::{Associative, Commutative, Idempotent, Identity, Magma};
+
+/// 最小公倍数を演算とする
+///
+/// 負の`a`では`op(a, a)` = `op(a, 1)` = |`a`|となるため, `Idempotent`と`Identity`は符号なし整数に限る
+pub struct Lcm<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+impl<T> Magma for Lcm<T>
+where
+    T: num::Integer,
+{
+    type T = T;
+
+    #[inline]
+    fn op(a: &T, b: &T) -> T {
+        a.lcm(b)
+    }
+}
+impl<T> Associative for Lcm<T> where Lcm<T>: Magma<T = T> {}
+impl<T> Commutative for Lcm<T> where Lcm<T>: Magma<T = T> {}
+impl<T: num::Unsigned> Idempotent for Lcm<T> where Lcm<T>: Magma<T = T> {}
+impl<T> Identity for Lcm<T>
+where
+    T: num::Integer + num::Unsigned,
+{
+    #[inline]
+    fn e() -> T {
+        T::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::segment_tree::SegmentTree;
+
+    #[test]
+    fn lcm_works() {
+        let lcm = SegmentTree::<Lcm<u64>>::from([12_u64, 18, 30, 42, 7]);
+
+        assert_eq!(lcm.prod(..3), 180);
+        assert_eq!(lcm.prod(3..3), 1);
+        assert_eq!(Lcm::<i64>::op(&-6, &1), 6);
+    }
+}
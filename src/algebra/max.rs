@@ -1,4 +1,4 @@
-use super::{Associative, Commutative, Idempotent, Identity, Magma};
+use super::{Action, Associative, Commutative, Idempotent, Identity, Magma};
 
 pub struct Max<T> {
     _p: std::marker::PhantomData<fn() -> T>,
@@ -31,3 +31,12 @@ where
         T::min_value()
     }
 }
+impl<T> Action<Max<T>> for Max<T>
+where
+    Max<T>: Identity<T = T>,
+{
+    #[inline]
+    fn act(f: &T, a: &T, _: usize) -> T {
+        Self::op(f, a)
+    }
+}
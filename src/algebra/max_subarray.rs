@@ -0,0 +1,139 @@
+use super::{Associative, Identity, Magma};
+
+/// 区間の総和, 最大接頭辞和, 最大接尾辞和, 最大部分配列和
+///
+/// 空の区間では総和以外が`T::min_value()`になる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Subarray<T> {
+    pub sum: T,
+    pub prefix: T,
+    pub suffix: T,
+    pub max: T,
+}
+impl<T: Copy> Subarray<T> {
+    /// 長さ1の区間`[x]`を表す値を生成する
+    ///
+    /// # Constraints
+    ///
+    /// * `x` != `T::min_value()`
+    #[inline]
+    #[must_use]
+    pub fn new(x: T) -> Self {
+        Subarray {
+            sum: x,
+            prefix: x,
+            suffix: x,
+            max: x,
+        }
+    }
+
+    /// 区間を左右反転した値を返す
+    #[inline]
+    #[must_use]
+    pub fn reverse(&self) -> Self {
+        Subarray {
+            sum: self.sum,
+            prefix: self.suffix,
+            suffix: self.prefix,
+            max: self.max,
+        }
+    }
+}
+
+/// 区間を連結する演算. 空でない連続部分列の和の最大値を求められる
+///
+/// 逆向きの連結は`Rev<MaxSubarray<T>>`か`Subarray::reverse`で扱う
+///
+/// # Constraints
+///
+/// * 計算途中の和が`T`に収まる
+/// * 要素も途中の和も`T::min_value()`にならない. `T::min_value()`は空の区間を表す値として使う
+pub struct MaxSubarray<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+impl<T: num::PrimInt + num::Signed> MaxSubarray<T> {
+    #[inline]
+    fn add(a: T, b: T) -> T {
+        if a == T::min_value() || b == T::min_value() {
+            T::min_value()
+        } else {
+            a + b
+        }
+    }
+}
+impl<T: num::PrimInt + num::Signed> Magma for MaxSubarray<T> {
+    type T = Subarray<T>;
+
+    #[inline]
+    fn op(a: &Subarray<T>, b: &Subarray<T>) -> Subarray<T> {
+        Subarray {
+            sum: a.sum + b.sum,
+            prefix: a.prefix.max(Self::add(a.sum, b.prefix)),
+            suffix: b.suffix.max(Self::add(a.suffix, b.sum)),
+            max: a.max.max(b.max).max(Self::add(a.suffix, b.prefix)),
+        }
+    }
+}
+impl<T> Associative for MaxSubarray<T> where MaxSubarray<T>: Magma<T = Subarray<T>> {}
+impl<T: num::PrimInt + num::Signed> Identity for MaxSubarray<T> {
+    #[inline]
+    fn e() -> Subarray<T> {
+        Subarray {
+            sum: T::zero(),
+            prefix: T::min_value(),
+            suffix: T::min_value(),
+            max: T::min_value(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Rev;
+    use super::*;
+    use crate::segment_tree::SegmentTree;
+
+    fn brute(v: &[i64]) -> Subarray<i64> {
+        let mut r = MaxSubarray::<i64>::e();
+        r.sum = v.iter().sum();
+        for l in 0..v.len() {
+            for k in l + 1..=v.len() {
+                let s = v[l..k].iter().sum();
+                r.max = r.max.max(s);
+                if l == 0 {
+                    r.prefix = r.prefix.max(s);
+                }
+                if k == v.len() {
+                    r.suffix = r.suffix.max(s);
+                }
+            }
+        }
+        r
+    }
+
+    #[test]
+    fn max_subarray_works() {
+        let mut v = vec![3_i64, -1, -4, 1, -5, 9, -2, 6, -5, -3];
+        let mut seg = SegmentTree::<MaxSubarray<i64>>::from(
+            v.iter().map(|&x| Subarray::new(x)).collect::<Vec<_>>(),
+        );
+        let mut rev = SegmentTree::<Rev<MaxSubarray<i64>>>::from(
+            v.iter().map(|&x| Subarray::new(x)).collect::<Vec<_>>(),
+        );
+
+        for (i, x) in [(5, -9), (0, -7), (3, 4), (9, 8)] {
+            v[i] = x;
+            seg.set(i, Subarray::new(x));
+            rev.set(i, Subarray::new(x));
+
+            for l in 0..=v.len() {
+                for r in l..=v.len() {
+                    let p = brute(&v[l..r]);
+                    assert_eq!(seg.prod(l..r), p);
+                    assert_eq!(rev.prod(l..r), p.reverse());
+                }
+            }
+        }
+        assert_eq!(seg.prod(..).max, 9);
+    }
+}
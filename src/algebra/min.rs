@@ -1,4 +1,4 @@
-use super::{Associative, Commutative, Idempotent, Identity, Magma};
+use super::{Action, Associative, Commutative, Idempotent, Identity, Magma};
 
 pub struct Min<T> {
     _p: std::marker::PhantomData<fn() -> T>,
@@ -31,3 +31,12 @@ where
         T::max_value()
     }
 }
+impl<T> Action<Min<T>> for Min<T>
+where
+    Min<T>: Identity<T = T>,
+{
+    #[inline]
+    fn act(f: &T, a: &T, _: usize) -> T {
+        Self::op(f, a)
+    }
+}
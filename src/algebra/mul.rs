@@ -0,0 +1,89 @@
+use super::{Associative, Commutative, Divisibility, Identity, Inverse, Magma};
+
+pub struct Mul<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+impl<T> Magma for Mul<T>
+where
+    for<'a> &'a T: std::ops::Mul<Output = T>,
+{
+    type T = T;
+
+    #[inline]
+    fn op(a: &Self::T, b: &Self::T) -> Self::T {
+        a * b
+    }
+}
+impl<T> Associative for Mul<T> where Mul<T>: Magma<T = T> {}
+impl<T: num::One> Identity for Mul<T>
+where
+    Mul<T>: Magma<T = T>,
+{
+    #[inline]
+    fn e() -> Self::T {
+        Self::T::one()
+    }
+}
+impl<T> Inverse for Mul<T>
+where
+    Mul<T>: Identity<T = T>,
+    for<'a> &'a T: num::traits::Inv<Output = T>,
+{
+    #[inline]
+    fn rev(a: &Self::T) -> Self::T {
+        num::traits::Inv::inv(a)
+    }
+}
+impl<T> Divisibility for Mul<T>
+where
+    Mul<T>: Magma<T = T>,
+    for<'a> &'a T: std::ops::Div<Output = T> + num::traits::Inv<Output = T>,
+{
+    #[inline]
+    fn div(a: &Self::T, b: &Self::T) -> Self::T {
+        a / b
+    }
+}
+
+/// `&T`同士の乗算が可換であることを表す. これを実装した`T`について`Mul<T>`は`Commutative`になる
+pub trait CommutativeMul {}
+impl<T: CommutativeMul> Commutative for Mul<T> where Mul<T>: Magma<T = T> {}
+
+macro_rules! ui {
+    ($($t: ty),*) => {
+        $(impl CommutativeMul for $t {})*
+    };
+}
+
+ui! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::laws;
+    use super::*;
+    use crate::modint::ModInt;
+    use crate::segment_tree::SegmentTree;
+
+    #[test]
+    fn mul_works() {
+        type Mint = ModInt<998244353>;
+
+        let seg = SegmentTree::<Mul<Mint>>::from([2, 3, 5, 7, 11].map(Mint::new));
+        assert_eq!(seg.prod(1..4), Mint::new(105));
+        assert_eq!(seg.prod(2..2), Mint::new(1));
+
+        let a = Mint::new(314159265);
+        assert_eq!(Mul::<Mint>::op(&a, &Mul::<Mint>::rev(&a)), Mint::new(1));
+        assert_eq!(
+            Mul::<Mint>::div(&Mint::new(6), &Mint::new(4)),
+            Mint::new(3) / Mint::new(2)
+        );
+
+        let mut x = 1_u64;
+        let gen = || {
+            x = x * 48271 % 2147483647;
+            Mint::new(x as u32)
+        };
+        assert_eq!(laws::commutative::<Mul<Mint>>(gen, 100), Ok(()));
+    }
+}
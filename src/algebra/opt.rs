@@ -0,0 +1,33 @@
+use super::{Associative, Commutative, Idempotent, Identity, Magma};
+
+/// 半群`M`に単位元`None`を追加したモノイド
+pub struct Opt<M> {
+    _p: std::marker::PhantomData<fn() -> M>,
+}
+impl<M: Magma> Magma for Opt<M>
+where
+    M::T: Clone,
+{
+    type T = Option<M::T>;
+
+    #[inline]
+    fn op(a: &Option<M::T>, b: &Option<M::T>) -> Option<M::T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(M::op(a, b)),
+            (Some(a), None) => Some(a.clone()),
+            (None, b) => b.clone(),
+        }
+    }
+}
+impl<M: Associative> Associative for Opt<M> where M::T: Clone {}
+impl<M: Commutative> Commutative for Opt<M> where M::T: Clone {}
+impl<M: Idempotent> Idempotent for Opt<M> where M::T: Clone {}
+impl<M: Magma> Identity for Opt<M>
+where
+    M::T: Clone,
+{
+    #[inline]
+    fn e() -> Option<M::T> {
+        None
+    }
+}
@@ -0,0 +1,70 @@
+use super::{Associative, Commutative, Divisibility, Idempotent, Identity, Inverse, Magma};
+
+/// 代数的構造の直積. 各成分ごとに演算を行う
+pub struct Product<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+
+macro_rules! ui {
+    ($($t: ident $i: tt),*) => {
+        impl<$($t: Magma),*> Magma for Product<($($t,)*)> {
+            type T = ($($t::T,)*);
+
+            #[inline]
+            fn op(a: &Self::T, b: &Self::T) -> Self::T {
+                ($($t::op(&a.$i, &b.$i),)*)
+            }
+        }
+        impl<$($t: Associative),*> Associative for Product<($($t,)*)> {}
+        impl<$($t: Commutative),*> Commutative for Product<($($t,)*)> {}
+        impl<$($t: Idempotent),*> Idempotent for Product<($($t,)*)> {}
+        impl<$($t: Identity),*> Identity for Product<($($t,)*)> {
+            #[inline]
+            fn e() -> Self::T {
+                ($($t::e(),)*)
+            }
+        }
+        impl<$($t: Inverse),*> Inverse for Product<($($t,)*)> {
+            #[inline]
+            fn rev(a: &Self::T) -> Self::T {
+                ($($t::rev(&a.$i),)*)
+            }
+        }
+        impl<$($t: Divisibility),*> Divisibility for Product<($($t,)*)> {
+            #[inline]
+            fn div(a: &Self::T, b: &Self::T) -> Self::T {
+                ($($t::div(&a.$i, &b.$i),)*)
+            }
+        }
+    };
+}
+
+ui! { A 0 }
+ui! { A 0, B 1 }
+ui! { A 0, B 1, C 2 }
+ui! { A 0, B 1, C 2, D 3 }
+ui! { A 0, B 1, C 2, D 3, E 4 }
+ui! { A 0, B 1, C 2, D 3, E 4, F 5 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Add, Max, Min};
+    use super::*;
+    use crate::segment_tree::SegmentTree;
+
+    #[test]
+    fn product_works() {
+        let v = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+        let seg = SegmentTree::<Product<(Add<i64>, Max<i64>, Min<i64>)>>::from(
+            v.map(|x| (x, x, x)).to_vec(),
+        );
+
+        assert_eq!(seg.prod(2..7), (21, 9, 1));
+        assert_eq!(seg.prod(6..), (16, 6, 2));
+        assert_eq!(seg.prod(3..3), (0, i64::MIN, i64::MAX));
+        assert_eq!(
+            <Product<(Add<i64>, Add<i32>)>>::div(&(5, 3), &(2, 7)),
+            (3, -4)
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use super::{Associative, Commutative, Divisibility, Idempotent, Identity, Inverse, Magma};
+
+/// 演算の左右を入れ替えた構造. `op(a, b)` = `M::op(b, a)`
+pub struct Rev<M> {
+    _p: std::marker::PhantomData<fn() -> M>,
+}
+impl<M: Magma> Magma for Rev<M> {
+    type T = M::T;
+
+    #[inline]
+    fn op(a: &M::T, b: &M::T) -> M::T {
+        M::op(b, a)
+    }
+}
+impl<M: Associative> Associative for Rev<M> {}
+impl<M: Commutative> Commutative for Rev<M> {}
+impl<M: Idempotent> Idempotent for Rev<M> {}
+impl<M: Identity> Identity for Rev<M> {
+    #[inline]
+    fn e() -> M::T {
+        M::e()
+    }
+}
+impl<M: Inverse> Inverse for Rev<M> {
+    #[inline]
+    fn rev(a: &M::T) -> M::T {
+        M::rev(a)
+    }
+}
+impl<M: Divisibility + Commutative> Divisibility for Rev<M> {
+    #[inline]
+    fn div(a: &M::T, b: &M::T) -> M::T {
+        M::div(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Affine;
+    use super::*;
+    use crate::modint::ModInt;
+    use crate::segment_tree::SegmentTree;
+
+    #[test]
+    fn rev_works() {
+        type Mint = ModInt<998244353>;
+
+        let v = [(2, 3), (5, 7), (11, 13), (17, 19)].map(|(a, b)| (Mint::new(a), Mint::new(b)));
+        let seg = SegmentTree::<Affine<Mint>>::from(v);
+        let rev = SegmentTree::<Rev<Affine<Mint>>>::from(v);
+
+        let x = Mint::new(23);
+        let mut y = x;
+        for f in v[1..].iter() {
+            y = Affine::<Mint>::eval(f, &y);
+        }
+        assert_eq!(Affine::<Mint>::eval(&rev.prod(1..), &x), y);
+
+        let mut y = x;
+        for f in v[1..].iter().rev() {
+            y = Affine::<Mint>::eval(f, &y);
+        }
+        assert_eq!(Affine::<Mint>::eval(&seg.prod(1..), &x), y);
+        assert_eq!(rev.prod(2..2), Affine::<Mint>::e());
+    }
+}
@@ -0,0 +1,60 @@
+use super::{Associative, Commutative, Divisibility, Identity, Inverse, Magma};
+
+pub struct Xor<T> {
+    _p: std::marker::PhantomData<fn() -> T>,
+}
+impl<T> Magma for Xor<T>
+where
+    for<'a> &'a T: std::ops::BitXor<Output = T>,
+{
+    type T = T;
+
+    #[inline]
+    fn op(a: &Self::T, b: &Self::T) -> Self::T {
+        a ^ b
+    }
+}
+impl<T> Associative for Xor<T> where Xor<T>: Magma<T = T> {}
+impl<T> Commutative for Xor<T> where Xor<T>: Magma<T = T> {}
+impl<T: Default> Identity for Xor<T>
+where
+    Xor<T>: Magma<T = T>,
+{
+    #[inline]
+    fn e() -> Self::T {
+        Self::T::default()
+    }
+}
+impl<T: Clone> Inverse for Xor<T>
+where
+    Xor<T>: Identity<T = T>,
+{
+    #[inline]
+    fn rev(a: &Self::T) -> Self::T {
+        a.clone()
+    }
+}
+impl<T> Divisibility for Xor<T>
+where
+    Xor<T>: Magma<T = T>,
+{
+    #[inline]
+    fn div(a: &Self::T, b: &Self::T) -> Self::T {
+        Self::op(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::segment_tree::SegmentTree;
+
+    #[test]
+    fn xor_works() {
+        let xor = SegmentTree::<Xor<u8>>::from([0b1100_u8, 0b1010, 0b0110]);
+
+        assert_eq!(xor.prod(..), 0);
+        assert_eq!(xor.prod(1..), 0b1100);
+        assert_eq!(Xor::<u8>::div(&0b1100, &0b1010), 0b0110);
+    }
+}
@@ -0,0 +1,267 @@
+use super::modint::ModInt;
+
+/// `a`^`b` mod `m`を返す
+const fn pow_mod(a: u32, mut b: u32, m: u32) -> u32 {
+    let mut g = a as u64 % m as u64;
+    let mut r = 1 % m as u64;
+    while b != 0 {
+        if b & 1 == 1 {
+            r = r * g % m as u64;
+        }
+        g = g * g % m as u64;
+        b >>= 1;
+    }
+    r as u32
+}
+
+/// 素数`m`の最小の原始根を返す
+///
+/// # Constraints
+///
+/// * `m`は素数
+///
+/// # Complexity
+///
+/// * *O*(√*m*)
+#[must_use]
+pub const fn primitive_root(m: u32) -> u32 {
+    if m == 2 {
+        return 1;
+    }
+
+    let mut p = [0; 10];
+    let mut c = 0;
+    let mut x = m - 1;
+    let mut i = 2;
+    while i * i <= x {
+        if x.is_multiple_of(i) {
+            p[c] = i;
+            c += 1;
+            while x.is_multiple_of(i) {
+                x /= i;
+            }
+        }
+        i += 1;
+    }
+    if x > 1 {
+        p[c] = x;
+        c += 1;
+    }
+
+    let mut g = 2;
+    loop {
+        let mut j = 0;
+        while j < c && pow_mod(g, (m - 1) / p[j], m) != 1 {
+            j += 1;
+        }
+        if j == c {
+            return g;
+        }
+        g += 1;
+    }
+}
+
+struct Roots<const N: u32>;
+
+impl<const N: u32> Roots<N> {
+    /// `N` - 1を割り切る最大の2冪の指数
+    const RANK: usize = (N - 1).trailing_zeros() as usize;
+    /// `ROOT[i]`は1の原始2^`i`乗根
+    const ROOT: [ModInt<N>; 32] = {
+        let g = ModInt::<N>::new(primitive_root(N)).pow(((N - 1) >> Self::RANK) as u64);
+        let mut r = [ModInt::new(0); 32];
+        let mut i = 0;
+        while i <= Self::RANK {
+            r[i] = g.pow(1 << (Self::RANK - i));
+            i += 1;
+        }
+        r
+    };
+    /// `IROOT[i]`は`ROOT[i]`の逆元
+    const IROOT: [ModInt<N>; 32] = {
+        let mut r = [ModInt::new(0); 32];
+        let mut i = 0;
+        while i <= Self::RANK {
+            r[i] = Self::ROOT[i].inv();
+            i += 1;
+        }
+        r
+    };
+}
+
+/// 数論変換をその場で行う. 結果はビット反転した順に並ぶ
+///
+/// # Constraints
+///
+/// * `N`は素数
+/// * `a`の長さは2^`k`で, 2^`k`は`N` - 1を割り切る
+///
+/// # Complexity
+///
+/// * *Θ*(*n* log *n*)
+pub fn ntt<const N: u32>(a: &mut [ModInt<N>]) {
+    let n = a.len();
+    debug_assert!(n.is_power_of_two());
+    debug_assert!(n.trailing_zeros() as usize <= Roots::<N>::RANK);
+
+    let mut m = n >> 1;
+    while m != 0 {
+        let w = Roots::<N>::ROOT[(m << 1).trailing_zeros() as usize];
+        for s in (0..n).step_by(m << 1) {
+            let mut z = ModInt::new(1);
+            for i in s..(s + m) {
+                let (x, y) = (a[i], a[i + m]);
+                a[i] = x + y;
+                a[i + m] = (x - y) * z;
+                z *= w;
+            }
+        }
+        m >>= 1;
+    }
+}
+
+/// `ntt`の逆変換をその場で行う. 入力はビット反転した順に並んでいるとする
+///
+/// # Constraints
+///
+/// * `N`は素数
+/// * `a`の長さは2^`k`で, 2^`k`は`N` - 1を割り切る
+///
+/// # Complexity
+///
+/// * *Θ*(*n* log *n*)
+pub fn intt<const N: u32>(a: &mut [ModInt<N>]) {
+    let n = a.len();
+    debug_assert!(n.is_power_of_two());
+    debug_assert!(n.trailing_zeros() as usize <= Roots::<N>::RANK);
+
+    let mut m = 1;
+    while m < n {
+        let w = Roots::<N>::IROOT[(m << 1).trailing_zeros() as usize];
+        for s in (0..n).step_by(m << 1) {
+            let mut z = ModInt::new(1);
+            for i in s..(s + m) {
+                let (x, y) = (a[i], a[i + m] * z);
+                a[i] = x + y;
+                a[i + m] = x - y;
+                z *= w;
+            }
+        }
+        m <<= 1;
+    }
+
+    let r = ModInt::new(n as u32).inv();
+    a.iter_mut().for_each(|x| *x *= r);
+}
+
+/// 畳み込み`c[k]` = Σ`a[i]`·`b[k - i]`を返す
+///
+/// # Constraints
+///
+/// * `N`は素数
+/// * `a`と`b`が空でなければ, |`a`| + |`b`| - 1以上の最小の2冪が`N` - 1を割り切る
+///
+/// # Complexity
+///
+/// * *O*((*n* + *m*) log (*n* + *m*))
+#[must_use]
+pub fn convolution<const N: u32>(a: &[ModInt<N>], b: &[ModInt<N>]) -> Vec<ModInt<N>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let l = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= 60 {
+        let mut c = vec![ModInt::new(0); l];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                c[i + j] += x * y;
+            }
+        }
+        return c;
+    }
+
+    let n = l.next_power_of_two();
+    let mut f = a.to_vec();
+    let mut g = b.to_vec();
+    f.resize(n, ModInt::new(0));
+    g.resize(n, ModInt::new(0));
+    ntt(&mut f);
+    ntt(&mut g);
+    f.iter_mut().zip(g.iter()).for_each(|(x, y)| *x *= *y);
+    intt(&mut f);
+    f.truncate(l);
+    f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive<const N: u32>(a: &[ModInt<N>], b: &[ModInt<N>]) -> Vec<ModInt<N>> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let mut c = vec![ModInt::new(0); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                c[i + j] += x * y;
+            }
+        }
+        c
+    }
+
+    fn check<const N: u32>(seed: u64) {
+        let mut x = seed;
+        let mut rand = move || {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x
+        };
+        for (n, m) in [
+            (0, 5),
+            (1, 1),
+            (3, 70),
+            (61, 61),
+            (100, 200),
+            (255, 257),
+            (1000, 1),
+        ] {
+            let a: Vec<_> = (0..n).map(|_| ModInt::<N>::new(rand() as u32)).collect();
+            let b: Vec<_> = (0..m).map(|_| ModInt::<N>::new(rand() as u32)).collect();
+            assert_eq!(convolution(&a, &b), naive(&a, &b));
+        }
+    }
+
+    #[test]
+    fn primitive_root_works() {
+        const G: u32 = primitive_root(998244353);
+        assert_eq!(G, 3);
+        assert_eq!(primitive_root(2), 1);
+        assert_eq!(primitive_root(7), 3);
+        assert_eq!(primitive_root(167772161), 3);
+        assert_eq!(primitive_root(469762049), 3);
+        assert_eq!(primitive_root(754974721), 11);
+    }
+
+    #[test]
+    fn convolution_works() {
+        check::<998244353>(1);
+        check::<167772161>(2);
+        check::<469762049>(3);
+        check::<754974721>(4);
+    }
+
+    #[test]
+    fn ntt_roundtrip() {
+        type Mint = ModInt<998244353>;
+
+        let v: Vec<_> = (0..16).map(|i| Mint::new(i * i + 1)).collect();
+        let mut a = v.clone();
+        ntt(&mut a);
+        assert_eq!(a[0], v.iter().copied().sum());
+        intt(&mut a);
+        assert_eq!(a, v);
+    }
+}
@@ -0,0 +1,157 @@
+use super::algebra::Associative;
+
+/// Disjoint Sparse Table
+pub struct DisjointSparseTable<M: Associative> {
+    d: Box<[Box<[M::T]>]>,
+}
+
+impl<M: Associative, V: Into<Vec<M::T>>> From<V> for DisjointSparseTable<M>
+where
+    M::T: Clone,
+{
+    /// # Complexity
+    ///
+    /// * *Θ*(*n* log *n*)
+    fn from(value: V) -> Self {
+        let v: Vec<M::T> = value.into();
+        let l = v.len();
+
+        let mut d = Vec::new();
+        let mut b = 1;
+        while b < l {
+            let mut t = v.clone();
+            for s in (0..l).step_by(b << 1) {
+                let m = (s + b).min(l);
+                for i in (s..(m - 1)).rev() {
+                    t[i] = M::op(&v[i], &t[i + 1]);
+                }
+                for i in (m + 1)..(s + (b << 1)).min(l) {
+                    t[i] = M::op(&t[i - 1], &v[i]);
+                }
+            }
+            d.push(t.into_boxed_slice());
+            b <<= 1;
+        }
+        d.insert(0, v.into_boxed_slice());
+
+        DisjointSparseTable { d: d.into() }
+    }
+}
+
+impl<M: Associative> std::ops::Deref for DisjointSparseTable<M> {
+    type Target = [M::T];
+
+    fn deref(&self) -> &[M::T] {
+        &self.d[0]
+    }
+}
+
+impl<M: Associative> DisjointSparseTable<M> {
+    /// 要素の総数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.d[0].len()
+    }
+
+    /// 空のテーブルかどうか調べる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.d[0].is_empty()
+    }
+
+    /// 区間での総積を取得する. 空の区間の場合はNoneを返す
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[must_use]
+    pub fn prod<R: std::ops::RangeBounds<usize>>(&self, range: R) -> Option<M::T>
+    where
+        M::T: Clone,
+    {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(s <= e && e <= self.len());
+
+        if s == e {
+            return None;
+        }
+        let e = e - 1;
+        if s == e {
+            return Some(self.d[0][s].clone());
+        }
+
+        let k = (usize::BITS - (s ^ e).leading_zeros()) as usize;
+        Some(M::op(&self.d[k][s], &self.d[k][e]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::{Add, Magma};
+    use super::*;
+    use crate::modint::ModInt;
+
+    #[test]
+    fn disjoint_sparse_table_works() {
+        let v = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 3, 8];
+        let st = DisjointSparseTable::<Add<ModInt<998244353>>>::from(v.map(ModInt::new));
+
+        for l in 0..v.len() {
+            for r in (l + 1)..=v.len() {
+                assert_eq!(st.prod(l..r).unwrap().val(), v[l..r].iter().sum());
+            }
+        }
+        assert_eq!(st.prod(4..4), None);
+    }
+
+    #[test]
+    fn non_commutative() {
+        struct Concat;
+        impl Magma for Concat {
+            type T = String;
+
+            fn op(a: &String, b: &String) -> String {
+                format!("{a}{b}")
+            }
+        }
+        impl Associative for Concat {}
+
+        let st = DisjointSparseTable::<Concat>::from(
+            "abracadabra".chars().map(String::from).collect::<Vec<_>>(),
+        );
+        assert_eq!(st.prod(..), Some("abracadabra".to_string()));
+        assert_eq!(st.prod(3..8), Some("acada".to_string()));
+        assert_eq!(st.prod(5..=5), Some("a".to_string()));
+    }
+
+    #[test]
+    fn case_empty() {
+        let st = DisjointSparseTable::<Add<i32>>::from([]);
+
+        assert_eq!(st.len(), 0);
+        assert_eq!(st.prod(..), None);
+    }
+}
@@ -0,0 +1,218 @@
+use super::algebra::{Associative, Commutative, Identity};
+
+/// 双対セグメントツリー
+///
+/// 区間の各要素`a`を`op(x, a)`で置き換える操作と, 一点の値の取得ができる
+pub struct DualSegmentTree<M: Associative + Identity> {
+    d: Box<[M::T]>,
+    s: usize,
+    h: u32,
+    l: usize,
+}
+
+impl<M: Associative + Identity, V: Into<Vec<M::T>>> From<V> for DualSegmentTree<M> {
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    fn from(value: V) -> Self {
+        let v: Vec<M::T> = value.into();
+        let l = v.len();
+        let s = l.next_power_of_two();
+
+        let mut d = Vec::with_capacity(s << 1);
+        for _ in 0..s {
+            d.push(M::e());
+        }
+        d.extend(v);
+        for _ in l..s {
+            d.push(M::e());
+        }
+
+        DualSegmentTree {
+            d: d.into(),
+            s,
+            h: s.trailing_zeros(),
+            l,
+        }
+    }
+}
+
+impl<M: Associative + Identity> DualSegmentTree<M> {
+    /// 要素の総数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.l
+    }
+
+    /// 空のセグ木かどうか調べる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.l == 0
+    }
+
+    #[inline]
+    fn push(&mut self, k: usize) {
+        let f = std::mem::replace(&mut self.d[k], M::e());
+        self.d[k << 1] = M::op(&f, &self.d[k << 1]);
+        self.d[(k << 1) | 1] = M::op(&f, &self.d[(k << 1) | 1]);
+    }
+
+    #[inline]
+    fn bounds<R: std::ops::RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(s <= e && e <= self.len());
+
+        (s + self.s, e + self.s)
+    }
+
+    fn apply_nodes(&mut self, mut l: usize, mut r: usize, x: &M::T) {
+        while l < r {
+            if l & 1 == 1 {
+                self.d[l] = M::op(x, &self.d[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                self.d[r] = M::op(x, &self.d[r]);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+    }
+
+    /// 区間の各要素`a`を`op(x, a)`で置き換える
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    pub fn apply<R: std::ops::RangeBounds<usize>>(&mut self, range: R, x: M::T) {
+        let (l, r) = self.bounds(range);
+        if l == r {
+            return;
+        }
+
+        for i in (1..=self.h).rev() {
+            if (l >> i) << i != l {
+                self.push(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.push((r - 1) >> i);
+            }
+        }
+        self.apply_nodes(l, r, &x);
+    }
+
+    /// 値を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `index` < `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(log *n*)
+    #[must_use]
+    pub fn get(&self, index: usize) -> M::T {
+        debug_assert!(index < self.len());
+
+        let p = index + self.s;
+        let mut r = M::e();
+        for i in (0..=self.h).rev() {
+            r = M::op(&r, &self.d[p >> i]);
+        }
+        r
+    }
+}
+
+impl<M: Associative + Commutative + Identity> DualSegmentTree<M> {
+    /// [`apply`](DualSegmentTree::apply)を可換な演算について行う. 遅延させた値の伝播を省略する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    pub fn apply_commutative<R: std::ops::RangeBounds<usize>>(&mut self, range: R, x: M::T) {
+        let (l, r) = self.bounds(range);
+        self.apply_nodes(l, r, &x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::{Add, Associative, Identity, Magma, Min};
+    use super::*;
+
+    #[test]
+    fn dual_segment_tree_works() {
+        let mut seg = DualSegmentTree::<Min<i32>>::from([8, 5, 7, 3, 9, 6, 4]);
+        seg.apply(1..5, 6);
+        seg.apply_commutative(4.., 5);
+        seg.apply(..=1, 2);
+
+        let v = (0..seg.len()).map(|i| seg.get(i)).collect::<Vec<_>>();
+        assert_eq!(v, [2, 2, 6, 3, 5, 5, 4]);
+
+        let mut seg = DualSegmentTree::<Add<i64>>::from([0; 5]);
+        seg.apply_commutative(1..4, 3);
+        seg.apply(2.., -1);
+
+        let v = (0..seg.len()).map(|i| seg.get(i)).collect::<Vec<_>>();
+        assert_eq!(v, [0, 3, 2, 2, -1]);
+    }
+
+    #[test]
+    fn non_commutative() {
+        /// 代入
+        struct Assign;
+        impl Magma for Assign {
+            type T = Option<i32>;
+
+            fn op(a: &Option<i32>, b: &Option<i32>) -> Option<i32> {
+                a.or(*b)
+            }
+        }
+        impl Associative for Assign {}
+        impl Identity for Assign {
+            fn e() -> Option<i32> {
+                None
+            }
+        }
+
+        let mut seg = DualSegmentTree::<Assign>::from([Some(0); 6]);
+        seg.apply(..4, Some(1));
+        seg.apply(2..5, Some(2));
+        seg.apply(3..4, Some(3));
+        seg.apply(1..3, Some(4));
+
+        let v = (0..seg.len())
+            .map(|i| seg.get(i).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(v, [1, 4, 4, 3, 2, 0]);
+    }
+}
@@ -0,0 +1,211 @@
+use super::algebra::{Associative, Identity};
+
+struct Node<T> {
+    v: T,
+    c: [usize; 2],
+}
+
+/// 動的セグメントツリー
+///
+/// 巨大な添字の範囲に対して, 値が設定された位置のノードのみを生成する
+pub struct DynamicSegmentTree<K, M: Associative + Identity> {
+    d: Vec<Node<M::T>>,
+    l: K,
+    r: K,
+}
+
+impl<K: num::PrimInt, M: Associative + Identity> DynamicSegmentTree<K, M> {
+    /// 添字の範囲が`range`で, 全ての値が`e`であるセグ木を生成する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` < `r`
+    /// * `r - l`が`K`で表せる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[must_use]
+    pub fn new(range: std::ops::Range<K>) -> Self {
+        debug_assert!(range.start < range.end);
+
+        DynamicSegmentTree {
+            d: vec![Node {
+                v: M::e(),
+                c: [0, 0],
+            }],
+            l: range.start,
+            r: range.end,
+        }
+    }
+
+    #[inline]
+    fn mid(l: K, r: K) -> K {
+        l + ((r - l) >> 1)
+    }
+
+    fn update(&mut self, k: usize, l: K, r: K, index: K, value: M::T, e: &M::T) {
+        if r - l == K::one() {
+            self.d[k].v = value;
+            return;
+        }
+        let m = Self::mid(l, r);
+        let (c, l, r) = if index < m { (0, l, m) } else { (1, m, r) };
+        if self.d[k].c[c] == 0 {
+            self.d[k].c[c] = self.d.len();
+            self.d.push(Node {
+                v: M::e(),
+                c: [0, 0],
+            });
+        }
+        self.update(self.d[k].c[c], l, r, index, value, e);
+
+        let [a, b] = self.d[k].c;
+        let a = if a == 0 { e } else { &self.d[a].v };
+        let b = if b == 0 { e } else { &self.d[b].v };
+        self.d[k].v = M::op(a, b);
+    }
+
+    /// 値を変更する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `index` < `r`
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(log (*r* - *l*))
+    pub fn set(&mut self, index: K, value: M::T) {
+        debug_assert!(self.l <= index && index < self.r);
+
+        self.update(0, self.l, self.r, index, value, &M::e());
+    }
+
+    /// 値を取得する. 値が一度も設定されていない場合はNoneを返す
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `index` < `r`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log (*r* - *l*))
+    #[must_use]
+    pub fn get(&self, index: K) -> Option<&M::T> {
+        debug_assert!(self.l <= index && index < self.r);
+
+        let mut k = 0;
+        let mut l = self.l;
+        let mut r = self.r;
+        while r - l > K::one() {
+            let m = Self::mid(l, r);
+            k = if index < m {
+                r = m;
+                self.d[k].c[0]
+            } else {
+                l = m;
+                self.d[k].c[1]
+            };
+            if k == 0 {
+                return None;
+            }
+        }
+        Some(&self.d[k].v)
+    }
+
+    fn fold(&self, k: usize, l: K, r: K, s: K, e: K, p: &mut M::T) {
+        if s <= l && r <= e {
+            *p = M::op(p, &self.d[k].v);
+            return;
+        }
+        let m = Self::mid(l, r);
+        let [a, b] = self.d[k].c;
+        if a != 0 && s < m {
+            self.fold(a, l, m, s, e, p);
+        }
+        if b != 0 && m < e {
+            self.fold(b, m, r, s, e, p);
+        }
+    }
+
+    /// 区間での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `s` <= `e` <= `r`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log (*r* - *l*))
+    #[must_use]
+    pub fn prod<R: std::ops::RangeBounds<K>>(&self, range: R) -> M::T {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + K::one(),
+            std::ops::Bound::Unbounded => self.l,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + K::one(),
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.r,
+        };
+        debug_assert!(self.l <= s && s <= e && e <= self.r);
+
+        let mut p = M::e();
+        if s < e {
+            self.fold(0, self.l, self.r, s, e, &mut p);
+        }
+        p
+    }
+
+    /// 全体での総積を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn all_prod(&self) -> &M::T {
+        &self.d[0].v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::{Add, Max};
+    use super::*;
+
+    #[test]
+    fn dynamic_segment_tree_works() {
+        let mut seg = DynamicSegmentTree::<i64, Add<i64>>::new(
+            -1_000_000_000_000_000_000..1_000_000_000_000_000_000,
+        );
+        seg.set(-314_159_265_358, 3);
+        seg.set(0, 1);
+        seg.set(271_828_182_845_904_523, 4);
+        seg.set(999_999_999_999_999_999, 5);
+
+        assert_eq!(seg.prod(..), 13);
+        assert_eq!(seg.prod(..0), 3);
+        assert_eq!(seg.prod(0..=271_828_182_845_904_523), 5);
+        assert_eq!(seg.prod(1..999_999_999_999_999_999), 4);
+        assert_eq!(seg.get(0), Some(&1));
+        assert_eq!(seg.get(1), None);
+
+        seg.set(0, 10);
+
+        assert_eq!(seg.prod(-1..1), 10);
+        assert_eq!(*seg.all_prod(), 22);
+    }
+
+    #[test]
+    fn unsigned_index() {
+        let mut seg = DynamicSegmentTree::<u64, Max<i32>>::new(0..u64::MAX);
+        seg.set(u64::MAX - 1, 8);
+        seg.set(1 << 40, 3);
+
+        assert_eq!(seg.prod(..u64::MAX - 1), 3);
+        assert_eq!(seg.prod(..), 8);
+        assert_eq!(seg.prod(5..5), i32::MIN);
+    }
+}
@@ -0,0 +1,214 @@
+use super::algebra::{Associative, Commutative, Divisibility, Inverse};
+
+/// フェニック木 (Binary Indexed Tree)
+pub struct FenwickTree<M: Associative + Commutative + Inverse> {
+    d: Box<[M::T]>,
+}
+
+impl<M: Associative + Commutative + Inverse, V: Into<Vec<M::T>>> From<V> for FenwickTree<M> {
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    fn from(value: V) -> Self {
+        let mut d: Vec<M::T> = value.into();
+        for i in 1..=d.len() {
+            let j = i + (i & i.wrapping_neg());
+            if j <= d.len() {
+                d[j - 1] = M::op(&d[j - 1], &d[i - 1]);
+            }
+        }
+        FenwickTree { d: d.into() }
+    }
+}
+
+impl<M: Associative + Commutative + Inverse> FenwickTree<M> {
+    /// 全ての値が`e`である長さ`n`のフェニック木を生成する
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        let mut d = Vec::with_capacity(n);
+        for _ in 0..n {
+            d.push(M::e());
+        }
+        FenwickTree { d: d.into() }
+    }
+
+    /// 要素の総数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.d.len()
+    }
+
+    /// 空のフェニック木かどうか調べる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.d.is_empty()
+    }
+
+    /// `index`番目の値`a`を`op(a, x)`で置き換える
+    ///
+    /// # Constraints
+    ///
+    /// * `index` < `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    pub fn add(&mut self, index: usize, x: M::T) {
+        debug_assert!(index < self.len());
+
+        let mut i = index + 1;
+        while i <= self.len() {
+            self.d[i - 1] = M::op(&self.d[i - 1], &x);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// 区間`0..r`での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn prefix(&self, mut r: usize) -> M::T {
+        debug_assert!(r <= self.len());
+
+        let mut p = M::e();
+        while r != 0 {
+            p = M::op(&p, &self.d[r - 1]);
+            r &= r - 1;
+        }
+        p
+    }
+
+    /// 区間での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn sum<R: std::ops::RangeBounds<usize>>(&self, range: R) -> M::T
+    where
+        M: Divisibility,
+    {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(s <= e && e <= self.len());
+
+        M::div(&self.prefix(e), &self.prefix(s))
+    }
+
+    /// `w` <= `prefix(r + 1)`を満たす最小の`r`を返す. 存在しない場合は`n`を返す
+    ///
+    /// # Constraints
+    ///
+    /// * 全ての値が`e`以上
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn lower_bound(&self, w: &M::T) -> usize
+    where
+        M::T: Ord,
+    {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let mut r = 0;
+        let mut p = M::e();
+        let mut k = 1 << (usize::BITS - 1 - self.len().leading_zeros());
+        while k != 0 {
+            if r + k <= self.len() {
+                let t = M::op(&p, &self.d[r + k - 1]);
+                if t < *w {
+                    r += k;
+                    p = t;
+                }
+            }
+            k >>= 1;
+        }
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::Add;
+    use super::*;
+    use crate::modint::ModInt;
+
+    #[test]
+    fn fenwick_tree_works() {
+        let v = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9];
+        let mut ft = FenwickTree::<Add<i64>>::from(v);
+
+        for l in 0..=v.len() {
+            for r in l..=v.len() {
+                assert_eq!(ft.sum(l..r), v[l..r].iter().sum());
+            }
+        }
+
+        ft.add(3, 10);
+
+        assert_eq!(ft.prefix(3), 8);
+        assert_eq!(ft.prefix(4), 19);
+        assert_eq!(ft.sum(..), 87);
+    }
+
+    #[test]
+    fn lower_bound_works() {
+        let v = [3, 0, 4, 1, 5, 0, 2];
+        let ft = FenwickTree::<Add<i32>>::from(v);
+
+        assert_eq!(ft.lower_bound(&0), 0);
+        assert_eq!(ft.lower_bound(&3), 0);
+        assert_eq!(ft.lower_bound(&4), 2);
+        assert_eq!(ft.lower_bound(&8), 3);
+        assert_eq!(ft.lower_bound(&13), 4);
+        assert_eq!(ft.lower_bound(&14), 6);
+        assert_eq!(ft.lower_bound(&16), 7);
+    }
+
+    #[test]
+    fn modint() {
+        type Mint = ModInt<998244353>;
+
+        let mut ft = FenwickTree::<Add<Mint>>::new(5);
+        ft.add(1, Mint::new(998244350));
+        ft.add(3, Mint::new(5));
+
+        assert_eq!(ft.sum(1..4), Mint::new(2));
+        assert_eq!(ft.sum(2..), Mint::new(5));
+    }
+}
@@ -0,0 +1,198 @@
+use super::algebra::{Associative, Commutative, Divisibility, Inverse};
+
+/// 二次元フェニック木
+pub struct FenwickTree2D<M: Associative + Commutative + Inverse> {
+    d: Box<[M::T]>,
+    h: usize,
+    w: usize,
+}
+
+impl<M: Associative + Commutative + Inverse> FenwickTree2D<M> {
+    /// 全ての値が`e`である`h`×`w`のフェニック木を生成する
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*hw*)
+    #[must_use]
+    pub fn new(h: usize, w: usize) -> Self {
+        let mut d = Vec::with_capacity(h * w);
+        for _ in 0..(h * w) {
+            d.push(M::e());
+        }
+        FenwickTree2D { d: d.into(), h, w }
+    }
+
+    /// 行数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    /// 列数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    /// (`i`, `j`)の値`a`を`op(a, x)`で置き換える
+    ///
+    /// # Constraints
+    ///
+    /// * `i` < `h`
+    /// * `j` < `w`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *h* log *w*)
+    pub fn add(&mut self, i: usize, j: usize, x: M::T) {
+        debug_assert!(i < self.h);
+        debug_assert!(j < self.w);
+
+        let mut a = i + 1;
+        while a <= self.h {
+            let mut b = j + 1;
+            while b <= self.w {
+                let k = (a - 1) * self.w + b - 1;
+                self.d[k] = M::op(&self.d[k], &x);
+                b += b & b.wrapping_neg();
+            }
+            a += a & a.wrapping_neg();
+        }
+    }
+
+    /// 矩形`0..i`×`0..j`での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `i` <= `h`
+    /// * `j` <= `w`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *h* log *w*)
+    #[must_use]
+    pub fn prefix(&self, i: usize, j: usize) -> M::T {
+        debug_assert!(i <= self.h);
+        debug_assert!(j <= self.w);
+
+        let mut p = M::e();
+        let mut a = i;
+        while a != 0 {
+            let mut b = j;
+            while b != 0 {
+                p = M::op(&p, &self.d[(a - 1) * self.w + b - 1]);
+                b &= b - 1;
+            }
+            a &= a - 1;
+        }
+        p
+    }
+
+    /// 矩形`rows`×`cols`での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `rows`が`0..h`に含まれる
+    /// * `cols`が`0..w`に含まれる
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *h* log *w*)
+    #[must_use]
+    pub fn sum<R: std::ops::RangeBounds<usize>, C: std::ops::RangeBounds<usize>>(
+        &self,
+        rows: R,
+        cols: C,
+    ) -> M::T
+    where
+        M: Divisibility,
+    {
+        let t = match rows.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let b = match rows.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.h,
+        };
+        let l = match cols.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let r = match cols.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.w,
+        };
+        debug_assert!(t <= b && b <= self.h);
+        debug_assert!(l <= r && r <= self.w);
+
+        M::div(
+            &M::op(&self.prefix(b, r), &self.prefix(t, l)),
+            &M::op(&self.prefix(t, r), &self.prefix(b, l)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::Add;
+    use super::*;
+    use crate::modint::ModInt;
+
+    #[test]
+    fn fenwick_tree_2d_works() {
+        let mut v = [[0_i64; 6]; 5];
+        let mut ft = FenwickTree2D::<Add<i64>>::new(5, 6);
+
+        for (i, j, x) in [
+            (0, 0, 3),
+            (2, 3, 1),
+            (4, 5, 4),
+            (1, 4, -1),
+            (2, 3, 5),
+            (3, 0, 9),
+        ] {
+            ft.add(i, j, x);
+            v[i][j] += x;
+        }
+
+        for t in 0..=5 {
+            for b in t..=5 {
+                for l in 0..=6 {
+                    for r in l..=6 {
+                        let s: i64 = v[t..b].iter().map(|w| w[l..r].iter().sum::<i64>()).sum();
+                        assert_eq!(ft.sum(t..b, l..r), s);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn modint() {
+        type Mint = ModInt<998244353>;
+
+        let mut ft = FenwickTree2D::<Add<Mint>>::new(3, 3);
+        ft.add(0, 0, Mint::new(998244352));
+        ft.add(1, 1, Mint::new(3));
+        ft.add(2, 2, Mint::new(5));
+
+        assert_eq!(ft.sum(.., ..), Mint::new(7));
+        assert_eq!(ft.sum(..2, ..2), Mint::new(2));
+        assert_eq!(ft.sum(1.., 1..=1), Mint::new(3));
+    }
+}
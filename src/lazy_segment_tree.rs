@@ -0,0 +1,411 @@
+use super::algebra::{Action, Associative, Identity};
+
+/// 遅延評価セグメントツリー
+pub struct LazySegmentTree<M: Associative + Identity, F: Action<M>> {
+    d: Box<[M::T]>,
+    z: Box<[F::T]>,
+    s: usize,
+    h: u32,
+    l: usize,
+}
+
+impl<M: Associative + Identity, F: Action<M>, V: Into<Vec<M::T>>> From<V>
+    for LazySegmentTree<M, F>
+{
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    fn from(value: V) -> Self {
+        let v: Vec<M::T> = value.into();
+        let l = v.len();
+        let s = l.next_power_of_two();
+
+        let mut d = Vec::with_capacity(s << 1);
+        for _ in 0..s {
+            d.push(M::e());
+        }
+        d.extend(v);
+        for _ in l..s {
+            d.push(M::e());
+        }
+        for i in (1..s).rev() {
+            d[i] = M::op(&d[i << 1], &d[(i << 1) | 1]);
+        }
+
+        let mut z = Vec::with_capacity(s);
+        for _ in 0..s {
+            z.push(F::e());
+        }
+
+        LazySegmentTree {
+            d: d.into(),
+            z: z.into(),
+            s,
+            h: s.trailing_zeros(),
+            l,
+        }
+    }
+}
+
+impl<M: Associative + Identity, F: Action<M>> LazySegmentTree<M, F> {
+    /// 要素の総数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.l
+    }
+
+    /// 空のセグ木かどうか調べる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.l == 0
+    }
+
+    #[inline]
+    fn update(&mut self, k: usize) {
+        self.d[k] = M::op(&self.d[k << 1], &self.d[(k << 1) | 1]);
+    }
+
+    #[inline]
+    fn all_apply(&mut self, k: usize, f: &F::T) {
+        let w = self.s >> (usize::BITS - 1 - k.leading_zeros());
+        self.d[k] = F::act(f, &self.d[k], w);
+        if k < self.s {
+            self.z[k] = F::op(f, &self.z[k]);
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, k: usize) {
+        let f = std::mem::replace(&mut self.z[k], F::e());
+        self.all_apply(k << 1, &f);
+        self.all_apply((k << 1) | 1, &f);
+    }
+
+    fn push_range(&mut self, l: usize, r: usize) {
+        for i in (1..=self.h).rev() {
+            if (l >> i) << i != l {
+                self.push(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.push((r - 1) >> i);
+            }
+        }
+    }
+
+    /// 値を変更する
+    ///
+    /// # Constraints
+    ///
+    /// * `index` < `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(log *n*)
+    pub fn set(&mut self, index: usize, value: M::T) {
+        debug_assert!(index < self.len());
+
+        let p = index + self.s;
+        for i in (1..=self.h).rev() {
+            self.push(p >> i);
+        }
+        self.d[p] = value;
+        for i in 1..=self.h {
+            self.update(p >> i);
+        }
+    }
+
+    /// 値を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `index` < `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(log *n*)
+    #[must_use]
+    pub fn get(&mut self, index: usize) -> &M::T {
+        debug_assert!(index < self.len());
+
+        let p = index + self.s;
+        for i in (1..=self.h).rev() {
+            self.push(p >> i);
+        }
+        &self.d[p]
+    }
+
+    /// 区間での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn prod<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> M::T {
+        let l = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(l <= r && r <= self.len());
+
+        if l == r {
+            return M::e();
+        }
+
+        let mut l = l + self.s;
+        let mut r = r + self.s;
+        self.push_range(l, r);
+
+        let mut sl = M::e();
+        let mut sr = M::e();
+        while l < r {
+            if l & 1 == 1 {
+                sl = M::op(&sl, &self.d[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                sr = M::op(&self.d[r], &sr);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        M::op(&sl, &sr)
+    }
+
+    /// 全体での総積を返す. 空のセグ木の場合はNoneを返す
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(1)
+    #[inline]
+    #[must_use]
+    pub fn all_prod(&self) -> Option<&M::T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&self.d[1])
+        }
+    }
+
+    /// 区間の各要素に`f`を作用させる
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    pub fn apply<R: std::ops::RangeBounds<usize>>(&mut self, range: R, f: F::T) {
+        let l = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(l <= r && r <= self.len());
+
+        if l == r {
+            return;
+        }
+
+        let l = l + self.s;
+        let r = r + self.s;
+        self.push_range(l, r);
+
+        let mut a = l;
+        let mut b = r;
+        while a < b {
+            if a & 1 == 1 {
+                self.all_apply(a, &f);
+                a += 1;
+            }
+            if b & 1 == 1 {
+                b -= 1;
+                self.all_apply(b, &f);
+            }
+            a >>= 1;
+            b >>= 1;
+        }
+
+        for i in 1..=self.h {
+            if (l >> i) << i != l {
+                self.update(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.update((r - 1) >> i);
+            }
+        }
+    }
+
+    /// `f(prod(l..r))`が`true`となる最大の`r`を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `n`
+    /// * `f(e)` = `true`
+    /// * `f`は単調
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    pub fn max_right<P: Fn(&M::T) -> bool>(&mut self, l: usize, f: P) -> usize {
+        debug_assert!(l <= self.len());
+        debug_assert!(f(&M::e()));
+
+        if l == self.len() {
+            return l;
+        }
+
+        let mut l = l + self.s;
+        for i in (1..=self.h).rev() {
+            self.push(l >> i);
+        }
+        let mut p = M::e();
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let t = M::op(&p, &self.d[l]);
+            if !f(&t) {
+                while l < self.s {
+                    self.push(l);
+                    l <<= 1;
+                    let t = M::op(&p, &self.d[l]);
+                    if f(&t) {
+                        p = t;
+                        l += 1;
+                    }
+                }
+                return l - self.s;
+            }
+            p = t;
+            l += 1;
+            if l.is_power_of_two() {
+                return self.len();
+            }
+        }
+    }
+
+    /// `f(prod(l..r))`が`true`となる最小の`l`を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `r` <= `n`
+    /// * `f(e)` = `true`
+    /// * `f`は単調
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    pub fn min_left<P: Fn(&M::T) -> bool>(&mut self, r: usize, f: P) -> usize {
+        debug_assert!(r <= self.len());
+        debug_assert!(f(&M::e()));
+
+        if r == 0 {
+            return 0;
+        }
+
+        let mut r = r + self.s;
+        for i in (1..=self.h).rev() {
+            self.push((r - 1) >> i);
+        }
+        let mut p = M::e();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let t = M::op(&self.d[r], &p);
+            if !f(&t) {
+                while r < self.s {
+                    self.push(r);
+                    r = (r << 1) | 1;
+                    let t = M::op(&self.d[r], &p);
+                    if f(&t) {
+                        p = t;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.s;
+            }
+            p = t;
+            if r.is_power_of_two() {
+                return 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::{Add, Min};
+    use super::*;
+
+    #[test]
+    fn lazy_segment_tree_works() {
+        let mut seg = LazySegmentTree::<Add<i64>, Add<i64>>::from([3, 1, 4, 1, 5, 9, 2, 6, 5, 3]);
+        assert_eq!(seg.prod(2..7), 21);
+
+        seg.apply(1..5, 10);
+
+        assert_eq!(seg.prod(2..7), 51);
+        assert_eq!(*seg.get(4), 15);
+        assert_eq!(seg.all_prod(), Some(&79));
+
+        seg.set(3, 0);
+
+        assert_eq!(seg.prod(..4), 28);
+        assert_eq!(seg.max_right(0, |&v| v <= 28), 4);
+        assert_eq!(seg.max_right(0, |&v| v < 28), 2);
+        assert_eq!(seg.min_left(10, |&v| v <= 25), 5);
+        assert_eq!(seg.min_left(10, |&v| v < 1000), 0);
+    }
+
+    #[test]
+    fn range_add_range_min() {
+        let mut seg = LazySegmentTree::<Min<i32>, Add<i32>>::from([5, 2, 8, 6, 3]);
+        seg.apply(..2, 4);
+        seg.apply(3.., -1);
+
+        assert_eq!(seg.prod(..), 2);
+        assert_eq!(seg.prod(..3), 6);
+        assert_eq!(seg.max_right(0, |&v| v >= 6), 3);
+        assert_eq!(seg.min_left(3, |&v| v >= 7), 2);
+    }
+
+    #[test]
+    fn case_empty() {
+        let mut seg = LazySegmentTree::<Add<i32>, Add<i32>>::from([]);
+
+        assert_eq!(seg.len(), 0);
+        assert_eq!(seg.prod(..), 0);
+        assert_eq!(seg.all_prod(), None);
+        assert_eq!(seg.max_right(0, |_| true), 0);
+        assert_eq!(seg.min_left(0, |_| true), 0);
+    }
+}
@@ -1,10 +1,22 @@
+pub mod convolution;
+pub mod disjoint_sparse_table;
+pub mod dual_segment_tree;
+pub mod dynamic_segment_tree;
+pub mod fenwick_tree;
+pub mod fenwick_tree_2d;
 pub mod graph;
 pub mod hilbertmo;
+pub mod lazy_segment_tree;
 pub mod lis;
+pub mod matrix;
 pub mod persistent_array;
+pub mod persistent_segment_tree;
 pub mod radix_heap;
+pub mod range_fenwick_tree;
 pub mod rolling_hash;
 pub mod segment_tree;
+pub mod segment_tree_beats;
+pub mod sparse_table;
 pub mod z_algorithm;
 
 pub mod algebra;
@@ -0,0 +1,263 @@
+use super::algebra::{Identity, Magma, Semiring};
+
+/// 半環`S`の要素を成分とする行列
+pub struct Matrix<S: Semiring> {
+    d: Box<[S::T]>,
+    h: usize,
+    w: usize,
+}
+
+impl<S: Semiring> Clone for Matrix<S>
+where
+    S::T: Clone,
+{
+    fn clone(&self) -> Self {
+        Matrix {
+            d: self.d.clone(),
+            h: self.h,
+            w: self.w,
+        }
+    }
+}
+
+impl<S: Semiring> PartialEq for Matrix<S>
+where
+    S::T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.h == other.h && self.w == other.w && self.d == other.d
+    }
+}
+
+impl<S: Semiring> Eq for Matrix<S> where S::T: Eq {}
+
+impl<S: Semiring> std::fmt::Debug for Matrix<S>
+where
+    S::T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries((0..self.h).map(|i| &self[i]))
+            .finish()
+    }
+}
+
+impl<S: Semiring, V: Into<Vec<Vec<S::T>>>> From<V> for Matrix<S> {
+    /// # Constraints
+    ///
+    /// * 各行の長さが等しい
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*hw*)
+    fn from(value: V) -> Self {
+        let v: Vec<Vec<S::T>> = value.into();
+        let h = v.len();
+        let w = v.first().map_or(0, Vec::len);
+        let mut d = Vec::with_capacity(h * w);
+        for r in v {
+            debug_assert_eq!(r.len(), w);
+            d.extend(r);
+        }
+        Matrix { d: d.into(), h, w }
+    }
+}
+
+impl<S: Semiring> Matrix<S> {
+    /// 全ての成分が`Add::e()`である`h`×`w`の行列を生成する
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*hw*)
+    #[must_use]
+    pub fn new(h: usize, w: usize) -> Self {
+        let mut d = Vec::with_capacity(h * w);
+        for _ in 0..(h * w) {
+            d.push(S::Add::e());
+        }
+        Matrix { d: d.into(), h, w }
+    }
+
+    /// `n`次の単位行列を生成する
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*²)
+    #[must_use]
+    pub fn identity(n: usize) -> Self {
+        let mut r = Self::new(n, n);
+        for i in 0..n {
+            r.d[i * n + i] = S::Mul::e();
+        }
+        r
+    }
+
+    /// 行数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    /// 列数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    /// 正方行列の`n`乗を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `h` = `w`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(*h*³ log `n`)
+    #[must_use]
+    pub fn pow(&self, n: u64) -> Self
+    where
+        S::T: PartialEq,
+    {
+        debug_assert_eq!(self.h, self.w);
+
+        let mut r = Self::identity(self.h);
+        for i in (0..(u64::BITS - n.leading_zeros())).rev() {
+            r = &r * &r;
+            if (n >> i) & 1 == 1 {
+                r = &r * self;
+            }
+        }
+        r
+    }
+}
+
+impl<S: Semiring> std::ops::Mul for &Matrix<S>
+where
+    S::T: PartialEq,
+{
+    type Output = Matrix<S>;
+
+    /// 行列積を返す. `Add::e()`である成分は零元として計算を省略する
+    ///
+    /// # Constraints
+    ///
+    /// * `self.w` = `rhs.h`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(*hwm*)
+    fn mul(self, rhs: &Matrix<S>) -> Matrix<S> {
+        debug_assert_eq!(self.w, rhs.h);
+
+        let z = S::Add::e();
+        let mut r = Matrix::new(self.h, rhs.w);
+        for i in 0..self.h {
+            for k in 0..self.w {
+                let a = &self.d[i * self.w + k];
+                if *a == z {
+                    continue;
+                }
+                for j in 0..rhs.w {
+                    let b = &rhs.d[k * rhs.w + j];
+                    if *b == z {
+                        continue;
+                    }
+                    let c = &mut r.d[i * rhs.w + j];
+                    *c = S::Add::op(c, &S::Mul::op(a, b));
+                }
+            }
+        }
+        r
+    }
+}
+
+impl<S: Semiring> std::ops::Index<usize> for Matrix<S> {
+    type Output = [S::T];
+
+    fn index(&self, index: usize) -> &[S::T] {
+        debug_assert!(index < self.h);
+
+        &self.d[(self.w * index)..(self.w * (index + 1))]
+    }
+}
+
+impl<S: Semiring> std::ops::IndexMut<usize> for Matrix<S> {
+    fn index_mut(&mut self, index: usize) -> &mut [S::T] {
+        debug_assert!(index < self.h);
+
+        &mut self.d[(self.w * index)..(self.w * (index + 1))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::{Add, BitAnd, BitOr, Max, Min, Mul};
+    use super::*;
+    use crate::modint::ModInt;
+
+    #[test]
+    fn fibonacci() {
+        type Mint = ModInt<998244353>;
+
+        let m = Matrix::<(Add<Mint>, Mul<Mint>)>::from(vec![
+            vec![Mint::new(1), Mint::new(1)],
+            vec![Mint::new(1), Mint::new(0)],
+        ]);
+        assert_eq!(m.pow(10)[0][1], Mint::new(55));
+        assert_eq!(m.pow(0), Matrix::identity(2));
+
+        let mut f = Mint::new(0);
+        let mut g = Mint::new(1);
+        for _ in 0..1000 {
+            (f, g) = (g, f + g);
+        }
+        assert_eq!(m.pow(1000)[0][1], f);
+    }
+
+    #[test]
+    fn shortest_path() {
+        let inf = i64::MAX;
+        let mut m = Matrix::<(Min<i64>, Add<i64>)>::new(3, 3);
+        m[0][1] = 5;
+        m[1][2] = -2;
+        m[2][0] = 4;
+        m[0][2] = 10;
+
+        let m2 = &m * &m;
+        assert_eq!(m2[0].to_vec(), [14, inf, 3]);
+        assert_eq!(m2[1].to_vec(), [2, inf, inf]);
+        assert_eq!(m.pow(3)[0][0], 7);
+
+        let mut m = Matrix::<(Max<i64>, Add<i64>)>::new(2, 2);
+        m[0][0] = 1;
+        m[0][1] = 3;
+        m[1][0] = -1;
+        assert_eq!(m.pow(4)[0].to_vec(), [4, 6]);
+    }
+
+    #[test]
+    fn reachability() {
+        let m = Matrix::<(BitOr<bool>, BitAnd<bool>)>::from(vec![
+            vec![false, true, false, false],
+            vec![false, false, true, false],
+            vec![false, false, false, true],
+            vec![false, false, false, false],
+        ]);
+        let r = Matrix::<(BitOr<bool>, BitAnd<bool>)>::from(vec![vec![true, false, false, false]]);
+
+        assert_eq!((&r * &m.pow(2))[0].to_vec(), [false, false, true, false]);
+        assert_eq!((&r * &m.pow(4))[0].to_vec(), [false; 4]);
+        assert_eq!(m.width(), 4);
+        assert_eq!(r.height(), 1);
+    }
+}
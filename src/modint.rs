@@ -100,6 +100,24 @@ impl<const N: u32> One for ModInt<N> {
     }
 }
 
+impl<const N: u32> num::traits::Inv for ModInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn inv(self) -> Self {
+        ModInt::inv(&self)
+    }
+}
+
+impl<const N: u32> num::traits::Inv for &ModInt<N> {
+    type Output = ModInt<N>;
+
+    #[inline]
+    fn inv(self) -> ModInt<N> {
+        ModInt::inv(self)
+    }
+}
+
 impl<const N: u32> std::fmt::Display for ModInt<N> {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -199,6 +217,8 @@ impl<const N: u32> std::iter::Product for ModInt<N> {
     }
 }
 
+impl<const N: u32> crate::algebra::CommutativeMul for ModInt<N> {}
+
 macro_rules! ui {
     ($($t: ident, $i: ident, $u: ident, $j: ident),*) => ($(
         impl<const N: u32> $u for ModInt<N> {
@@ -240,6 +260,18 @@ ui! { Sub, sub, SubAssign, sub_assign }
 ui! { Mul, mul, MulAssign, mul_assign }
 ui! { Div, div, DivAssign, div_assign }
 
+mod dynamic;
+pub use dynamic::{Barrett, DynamicModInt};
+
+mod montgomery;
+pub use montgomery::MontgomeryModInt;
+
+mod modint64;
+pub use modint64::ModInt64;
+
+mod binomial;
+pub use binomial::Binomial;
+
 #[cfg(test)]
 mod tests {
     use super::*;
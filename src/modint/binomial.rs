@@ -0,0 +1,206 @@
+use super::ModInt;
+
+/// 階乗とその逆元の表. 必要に応じて表を伸ばす
+///
+/// # Constraints
+///
+/// * `N`は素数
+/// * 引数に現れる値は`N`未満
+#[derive(Clone, Debug)]
+pub struct Binomial<const N: u32> {
+    f: Vec<ModInt<N>>,
+    fi: Vec<ModInt<N>>,
+}
+
+impl<const N: u32> Default for Binomial<N> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const N: u32> Binomial<N> {
+    /// `n`!までの表を生成する
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*n* + log `N`)
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        let mut r = Binomial {
+            f: vec![ModInt::new(1)],
+            fi: vec![ModInt::new(1)],
+        };
+        r.reserve(n);
+        r
+    }
+
+    /// `n`!までの表を用意する
+    ///
+    /// # Complexity
+    ///
+    /// * `n`が表の長さ*l*未満なら *Θ*(1)
+    /// * そうでなければ, 伸ばした後の長さを*m*として *Θ*(*m* - *l* + log `N`)
+    /// * 長さは倍以上に伸ばすので, 引数の最大値を*M*とすると全呼び出しの合計は *O*(*M* + log *M* log `N`)
+    pub fn reserve(&mut self, n: usize) {
+        let l = self.f.len();
+        if n < l {
+            return;
+        }
+        debug_assert!(n < N as usize);
+
+        let m = (n + 1).max(l << 1).min(N as usize);
+        for i in l..m {
+            let x = self.f[i - 1] * ModInt::new(i as u32);
+            self.f.push(x);
+        }
+        self.fi.resize(m, ModInt::new(0));
+        self.fi[m - 1] = self.f[m - 1].inv();
+        for i in (l..(m - 1)).rev() {
+            self.fi[i] = self.fi[i + 1] * ModInt::new(i as u32 + 1);
+        }
+    }
+
+    /// `n`!を返す
+    ///
+    /// # Complexity
+    ///
+    /// * 表が足りていれば *Θ*(1). 足りなければ`reserve`の計算量が加わる
+    #[must_use]
+    pub fn fact(&mut self, n: usize) -> ModInt<N> {
+        self.reserve(n);
+        self.f[n]
+    }
+
+    /// 1 / `n`!を返す
+    ///
+    /// # Complexity
+    ///
+    /// * 表が足りていれば *Θ*(1). 足りなければ`reserve`の計算量が加わる
+    #[must_use]
+    pub fn inv_fact(&mut self, n: usize) -> ModInt<N> {
+        self.reserve(n);
+        self.fi[n]
+    }
+
+    /// 1 / `n`を返す. `n` = 0なら0を返す
+    ///
+    /// # Complexity
+    ///
+    /// * 表が足りていれば *Θ*(1). 足りなければ`reserve`の計算量が加わる
+    #[must_use]
+    pub fn inv(&mut self, n: usize) -> ModInt<N> {
+        if n == 0 {
+            return ModInt::new(0);
+        }
+        self.reserve(n);
+        self.fi[n] * self.f[n - 1]
+    }
+
+    /// 二項係数`n`C`k`を返す. `k` > `n`なら0を返す
+    ///
+    /// # Complexity
+    ///
+    /// * 表が足りていれば *Θ*(1). 足りなければ`reserve`の計算量が加わる
+    #[must_use]
+    pub fn comb(&mut self, n: usize, k: usize) -> ModInt<N> {
+        if k > n {
+            return ModInt::new(0);
+        }
+        self.reserve(n);
+        self.f[n] * self.fi[k] * self.fi[n - k]
+    }
+
+    /// 順列の数`n`P`k`を返す. `k` > `n`なら0を返す
+    ///
+    /// # Complexity
+    ///
+    /// * 表が足りていれば *Θ*(1). 足りなければ`reserve`の計算量が加わる
+    #[must_use]
+    pub fn perm(&mut self, n: usize, k: usize) -> ModInt<N> {
+        if k > n {
+            return ModInt::new(0);
+        }
+        self.reserve(n);
+        self.f[n] * self.fi[n - k]
+    }
+
+    /// 多項係数(`k₁` + `k₂` + ...)! / (`k₁`!`k₂`!...)を返す
+    ///
+    /// # Complexity
+    ///
+    /// * 表が足りていれば *Θ*(|`k`|). 足りなければ`reserve`の計算量が加わる
+    #[must_use]
+    pub fn multinomial(&mut self, k: &[usize]) -> ModInt<N> {
+        let n = k.iter().sum();
+        self.reserve(n);
+        k.iter().fold(self.f[n], |r, &x| r * self.fi[x])
+    }
+
+    /// 重複組合せの数`n`H`k`を返す. `n` = 0かつ`k` > 0なら0を返す
+    ///
+    /// # Complexity
+    ///
+    /// * 表が足りていれば *Θ*(1). 足りなければ`reserve`の計算量が加わる
+    #[must_use]
+    pub fn homogeneous(&mut self, n: usize, k: usize) -> ModInt<N> {
+        if n == 0 {
+            return ModInt::new((k == 0) as u32);
+        }
+        self.comb(n + k - 1, k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binomial_works() {
+        type Mint = ModInt<998244353>;
+
+        let mut b = Binomial::<998244353>::new(5);
+        assert_eq!(b.fact(5), Mint::new(120));
+        assert_eq!(b.fact(20), Mint::new(401576539));
+        assert_eq!(b.inv_fact(20) * b.fact(20), Mint::new(1));
+        assert_eq!(b.inv(3) * Mint::new(3), Mint::new(1));
+        assert_eq!(b.inv(0), Mint::new(0));
+
+        assert_eq!(b.comb(10, 3), Mint::new(120));
+        assert_eq!(b.comb(3, 10), Mint::new(0));
+        assert_eq!(b.comb(0, 0), Mint::new(1));
+        assert_eq!(b.perm(10, 3), Mint::new(720));
+        assert_eq!(b.perm(3, 4), Mint::new(0));
+        assert_eq!(b.multinomial(&[2, 3, 1]), Mint::new(60));
+        assert_eq!(b.multinomial(&[]), Mint::new(1));
+        assert_eq!(b.homogeneous(3, 2), Mint::new(6));
+        assert_eq!(b.homogeneous(0, 0), Mint::new(1));
+        assert_eq!(b.homogeneous(0, 2), Mint::new(0));
+
+        let mut c = Binomial::<998244353>::default();
+        for n in 0..50 {
+            for k in 0..=n {
+                assert_eq!(
+                    c.comb(n, k),
+                    if k == 0 || k == n {
+                        Mint::new(1)
+                    } else {
+                        b.comb(n - 1, k - 1) + b.comb(n - 1, k)
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn small_modulus() {
+        let mut b = Binomial::<7>::new(0);
+        assert_eq!(b.fact(6).val(), 6);
+        assert_eq!(b.comb(6, 3).val(), 6);
+        assert_eq!(
+            (1..7)
+                .map(|i| (b.inv(i) * ModInt::new(i as u32)).val())
+                .min(),
+            Some(1)
+        );
+    }
+}
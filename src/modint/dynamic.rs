@@ -0,0 +1,401 @@
+use num::{One, Zero};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Barrett reductionで`m`を法とする乗算を行う
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Barrett {
+    m: u32,
+    im: u64,
+}
+
+impl Barrett {
+    /// # Constraints
+    ///
+    /// * 1 <= `m` < 2³¹
+    #[inline]
+    #[must_use]
+    pub const fn new(m: u32) -> Self {
+        debug_assert!(1 <= m && m < 1 << 31);
+
+        Barrett {
+            m,
+            im: (u64::MAX / m as u64).wrapping_add(1),
+        }
+    }
+
+    /// 法を返す
+    #[inline]
+    #[must_use]
+    pub const fn modulus(&self) -> u32 {
+        self.m
+    }
+
+    /// `a`·`b` mod `m`を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `a` < `m`
+    /// * `b` < `m`
+    #[inline]
+    #[must_use]
+    pub const fn mul(&self, a: u32, b: u32) -> u32 {
+        let z = a as u64 * b as u64;
+        let x = ((z as u128 * self.im as u128) >> 64) as u64;
+        let v = z.wrapping_sub(x.wrapping_mul(self.m as u64)) as u32;
+        if self.m <= v {
+            v.wrapping_add(self.m)
+        } else {
+            v
+        }
+    }
+}
+
+thread_local! {
+    static BARRETT: std::cell::Cell<Barrett> = const { std::cell::Cell::new(Barrett::new(998244353)) };
+}
+
+/// 実行時に法を指定する`ModInt`. 法はスレッドごとに一つで, 初期値は998244353である
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub struct DynamicModInt(u32);
+
+impl DynamicModInt {
+    /// 法を`m`に変更する. 変更前に生成した値は使えなくなる
+    ///
+    /// # Constraints
+    ///
+    /// * 1 <= `m` < 2³¹
+    #[inline]
+    pub fn set_modulus(m: u32) {
+        BARRETT.with(|b| b.set(Barrett::new(m)));
+    }
+
+    /// 法を返す
+    #[inline]
+    #[must_use]
+    pub fn modulus() -> u32 {
+        Self::barrett().m
+    }
+
+    #[inline]
+    fn barrett() -> Barrett {
+        BARRETT.with(|b| b.get())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn new(v: u32) -> Self {
+        DynamicModInt(v % Self::modulus())
+    }
+
+    /// # Safety
+    ///
+    /// * `v` < 法
+    #[inline]
+    #[must_use]
+    pub unsafe fn new_unchecked(v: u32) -> Self {
+        debug_assert!(v < Self::modulus());
+
+        DynamicModInt(v)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn val(&self) -> u32 {
+        self.0
+    }
+
+    #[must_use]
+    pub fn inv(&self) -> Self {
+        debug_assert!(self.0 != 0);
+
+        let n = Self::modulus();
+        let mut a = self.0;
+        let mut b = n;
+        let mut u = 1_i64;
+        let mut v = 0;
+        while b != 0 {
+            let t = a / b;
+            a -= t * b;
+            (a, b) = (b, a);
+            u -= t as i64 * v;
+            (u, v) = (v, u);
+        }
+        DynamicModInt(u.rem_euclid(n as i64) as u32)
+    }
+
+    #[must_use]
+    pub fn pow(self, mut b: u64) -> Self {
+        let bt = Self::barrett();
+        let mut g = self.0;
+        let mut r = if b & 1 == 0 { 1 % bt.m } else { self.0 };
+        b >>= 1;
+        while b != 0 {
+            g = bt.mul(g, g);
+            if b & 1 == 1 {
+                r = bt.mul(r, g);
+            }
+            b >>= 1;
+        }
+        DynamicModInt(r)
+    }
+}
+
+impl num::FromPrimitive for DynamicModInt {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(DynamicModInt(n.rem_euclid(Self::modulus() as i64) as u32))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(DynamicModInt((n % Self::modulus() as u64) as u32))
+    }
+}
+
+impl num::ToPrimitive for DynamicModInt {
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.0 as i64)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(self.0 as u64)
+    }
+}
+
+impl Zero for DynamicModInt {
+    fn zero() -> Self {
+        DynamicModInt(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for DynamicModInt {
+    fn one() -> Self {
+        DynamicModInt(1 % Self::modulus())
+    }
+}
+
+impl num::traits::Inv for DynamicModInt {
+    type Output = Self;
+
+    #[inline]
+    fn inv(self) -> Self {
+        DynamicModInt::inv(&self)
+    }
+}
+
+impl num::traits::Inv for &DynamicModInt {
+    type Output = DynamicModInt;
+
+    #[inline]
+    fn inv(self) -> DynamicModInt {
+        DynamicModInt::inv(self)
+    }
+}
+
+impl std::fmt::Display for DynamicModInt {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for DynamicModInt {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<u32> for DynamicModInt {
+    #[inline]
+    fn from(value: u32) -> Self {
+        DynamicModInt::new(value)
+    }
+}
+
+impl From<DynamicModInt> for u32 {
+    #[inline]
+    fn from(value: DynamicModInt) -> u32 {
+        value.0
+    }
+}
+
+impl Add for DynamicModInt {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let n = Self::modulus();
+        let v = self.0 + rhs.0;
+        DynamicModInt(if v >= n { v - n } else { v })
+    }
+}
+
+impl Sub for DynamicModInt {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        let (v, f) = self.0.overflowing_sub(rhs.0);
+        DynamicModInt(if f {
+            v.wrapping_add(Self::modulus())
+        } else {
+            v
+        })
+    }
+}
+
+impl Mul for DynamicModInt {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        DynamicModInt(Self::barrett().mul(self.0, rhs.0))
+    }
+}
+
+impl Div for DynamicModInt {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        DynamicModInt(Self::barrett().mul(self.0, rhs.inv().0))
+    }
+}
+
+impl Neg for DynamicModInt {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        if self.0 != 0 {
+            self.0 = Self::modulus() - self.0;
+        }
+        self
+    }
+}
+
+impl Neg for &DynamicModInt {
+    type Output = DynamicModInt;
+
+    #[inline]
+    fn neg(self) -> DynamicModInt {
+        -*self
+    }
+}
+
+impl std::iter::Sum for DynamicModInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(DynamicModInt::zero(), |a, b| a + b)
+    }
+}
+
+impl std::iter::Product for DynamicModInt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(DynamicModInt::one(), |a, b| a * b)
+    }
+}
+
+impl crate::algebra::CommutativeMul for DynamicModInt {}
+
+macro_rules! ui {
+    ($($t: ident, $i: ident, $u: ident, $j: ident),*) => ($(
+        impl $u for DynamicModInt {
+            #[inline]
+            fn $j(&mut self, rhs: Self) {
+                *self = self.$i(rhs);
+            }
+        }
+
+        impl $t<DynamicModInt> for &DynamicModInt {
+            type Output = DynamicModInt;
+
+            #[inline]
+            fn $i(self, rhs: DynamicModInt) -> DynamicModInt {
+                (*self).$i(rhs)
+            }
+        }
+        impl $t<&DynamicModInt> for DynamicModInt {
+            type Output = DynamicModInt;
+
+            #[inline]
+            fn $i(self, rhs: &DynamicModInt) -> DynamicModInt {
+                self.$i(*rhs)
+            }
+        }
+        impl $t<&DynamicModInt> for &DynamicModInt {
+            type Output = DynamicModInt;
+
+            #[inline]
+            fn $i(self, rhs: &DynamicModInt) -> DynamicModInt {
+                (*self).$i(*rhs)
+            }
+        }
+    )*);
+}
+
+ui! { Add, add, AddAssign, add_assign }
+ui! { Sub, sub, SubAssign, sub_assign }
+ui! { Mul, mul, MulAssign, mul_assign }
+ui! { Div, div, DivAssign, div_assign }
+
+#[cfg(test)]
+mod tests {
+    use super::super::ModInt;
+    use super::*;
+    use num::FromPrimitive;
+
+    #[test]
+    fn dynamic_modint_works() {
+        type Mint = DynamicModInt;
+
+        let a = Mint::new(314159265);
+        let b = Mint::new(358979323);
+
+        assert_eq!(-a, Mint::new(684085088));
+        assert_eq!(a + b, Mint::new(673138588));
+        assert_eq!(a - b, Mint::new(953424295));
+        assert_eq!(a * b, Mint::new(525838123));
+        assert_eq!(a / b, Mint::new(465339227));
+
+        Mint::set_modulus(1_000_000_007);
+        assert_eq!(Mint::modulus(), 1_000_000_007);
+        assert_eq!(Mint::from_i64(-1), Some(Mint::new(1_000_000_006)));
+        assert_eq!(Mint::new(2).pow(1_000_000_006), Mint::one());
+        assert_eq!(
+            [2, 3, 7].map(Mint::new).into_iter().product::<Mint>().val(),
+            42
+        );
+
+        Mint::set_modulus(1);
+        assert_eq!(Mint::new(5) * Mint::new(3), Mint::zero());
+        assert_eq!(Mint::one(), Mint::zero());
+    }
+
+    #[test]
+    fn barrett() {
+        const N: u32 = 2147483647;
+
+        let mut x = 1_u64;
+        for m in [2, 3, 998244353, 1_000_000_007, N] {
+            let b = Barrett::new(m);
+            for _ in 0..1000 {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                let (p, q) = ((x % m as u64) as u32, ((x >> 32) % m as u64) as u32);
+                assert_eq!(b.mul(p, q) as u64, p as u64 * q as u64 % m as u64);
+            }
+        }
+
+        DynamicModInt::set_modulus(N);
+        let a = ModInt::<N>::new(123456789);
+        let b = ModInt::<N>::new(987654321);
+        assert_eq!(
+            (DynamicModInt::new(a.val()) * DynamicModInt::new(b.val())).val(),
+            (a * b).val()
+        );
+    }
+}
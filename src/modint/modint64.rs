@@ -0,0 +1,324 @@
+use num::{One, Zero};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// 法が`u64`の`ModInt`. 乗算は`u128`で行う
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub struct ModInt64<const N: u64>(u64);
+
+impl<const N: u64> ModInt64<N> {
+    #[inline]
+    #[must_use]
+    pub const fn new(v: u64) -> Self {
+        ModInt64(v % N)
+    }
+
+    /// # Safety
+    ///
+    /// * `v` < `N`
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(v: u64) -> Self {
+        debug_assert!(v < N);
+
+        ModInt64(v)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn val(&self) -> u64 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn inv(&self) -> Self {
+        debug_assert!(self.0 != 0);
+
+        let mut a = self.0;
+        let mut b = N;
+        let mut u = 1_i128;
+        let mut v = 0;
+        while b != 0 {
+            let t = a / b;
+            a -= t * b;
+            (a, b) = (b, a);
+            u -= t as i128 * v;
+            (u, v) = (v, u);
+        }
+        ModInt64(u.rem_euclid(N as i128) as u64)
+    }
+
+    #[must_use]
+    pub const fn pow(self, mut b: u64) -> Self {
+        let mut g = self.0;
+        let mut r = if b & 1 == 0 { 1 % N } else { self.0 };
+        b >>= 1;
+        while b != 0 {
+            g = (g as u128 * g as u128 % N as u128) as u64;
+            if b & 1 == 1 {
+                r = (r as u128 * g as u128 % N as u128) as u64;
+            }
+            b >>= 1;
+        }
+        ModInt64(r)
+    }
+}
+
+impl<const N: u64> num::FromPrimitive for ModInt64<N> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(ModInt64((n as i128).rem_euclid(N as i128) as u64))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(ModInt64(n % N))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(ModInt64(n.rem_euclid(N as i128) as u64))
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(ModInt64((n % N as u128) as u64))
+    }
+}
+
+impl<const N: u64> num::ToPrimitive for ModInt64<N> {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.try_into().ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(self.0)
+    }
+}
+
+impl<const N: u64> Zero for ModInt64<N> {
+    fn zero() -> Self {
+        ModInt64(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const N: u64> One for ModInt64<N> {
+    fn one() -> Self {
+        ModInt64(1 % N)
+    }
+}
+
+impl<const N: u64> num::traits::Inv for ModInt64<N> {
+    type Output = Self;
+
+    #[inline]
+    fn inv(self) -> Self {
+        ModInt64::inv(&self)
+    }
+}
+
+impl<const N: u64> num::traits::Inv for &ModInt64<N> {
+    type Output = ModInt64<N>;
+
+    #[inline]
+    fn inv(self) -> ModInt64<N> {
+        ModInt64::inv(self)
+    }
+}
+
+impl<const N: u64> std::fmt::Display for ModInt64<N> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const N: u64> std::fmt::Debug for ModInt64<N> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const N: u64> From<u64> for ModInt64<N> {
+    #[inline]
+    fn from(value: u64) -> Self {
+        ModInt64(value % N)
+    }
+}
+
+impl<const N: u64> From<ModInt64<N>> for u64 {
+    #[inline]
+    fn from(value: ModInt64<N>) -> u64 {
+        value.0
+    }
+}
+
+impl<const N: u64> Add for ModInt64<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let (v, f) = self.0.overflowing_add(rhs.0);
+        ModInt64(if f || v >= N { v.wrapping_sub(N) } else { v })
+    }
+}
+
+impl<const N: u64> Sub for ModInt64<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        let (v, f) = self.0.overflowing_sub(rhs.0);
+        ModInt64(if f { v.wrapping_add(N) } else { v })
+    }
+}
+
+impl<const N: u64> Mul for ModInt64<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        ModInt64(((self.0 as u128) * (rhs.0 as u128) % (N as u128)) as u64)
+    }
+}
+
+impl<const N: u64> Div for ModInt64<N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Mul::mul(self, rhs.inv())
+    }
+}
+
+impl<const N: u64> Neg for ModInt64<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        if self.0 != 0 {
+            self.0 = N - self.0;
+        }
+        self
+    }
+}
+
+impl<const N: u64> Neg for &ModInt64<N> {
+    type Output = ModInt64<N>;
+
+    #[inline]
+    fn neg(self) -> ModInt64<N> {
+        -*self
+    }
+}
+
+impl<const N: u64> std::iter::Sum for ModInt64<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(ModInt64::zero(), |a, b| a + b)
+    }
+}
+
+impl<const N: u64> std::iter::Product for ModInt64<N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(ModInt64::one(), |a, b| a * b)
+    }
+}
+
+impl<const N: u64> crate::algebra::CommutativeMul for ModInt64<N> {}
+
+macro_rules! ui {
+    ($($t: ident, $i: ident, $u: ident, $j: ident),*) => ($(
+        impl<const N: u64> $u for ModInt64<N> {
+            #[inline]
+            fn $j(&mut self, rhs: Self) {
+                *self = self.$i(rhs);
+            }
+        }
+
+        impl<const N: u64> $t<ModInt64<N>> for &ModInt64<N> {
+            type Output = ModInt64<N>;
+
+            #[inline]
+            fn $i(self, rhs: ModInt64<N>) -> ModInt64<N> {
+                (*self).$i(rhs)
+            }
+        }
+        impl<const N: u64> $t<&ModInt64<N>> for ModInt64<N> {
+            type Output = ModInt64<N>;
+
+            #[inline]
+            fn $i(self, rhs: &ModInt64<N>) -> ModInt64<N> {
+                self.$i(*rhs)
+            }
+        }
+        impl<const N: u64> $t<&ModInt64<N>> for &ModInt64<N> {
+            type Output = ModInt64<N>;
+
+            #[inline]
+            fn $i(self, rhs: &ModInt64<N>) -> ModInt64<N> {
+                (*self).$i(*rhs)
+            }
+        }
+    )*);
+}
+
+ui! { Add, add, AddAssign, add_assign }
+ui! { Sub, sub, SubAssign, sub_assign }
+ui! { Mul, mul, MulAssign, mul_assign }
+ui! { Div, div, DivAssign, div_assign }
+
+#[cfg(test)]
+mod tests {
+    use super::super::ModInt;
+    use super::*;
+    use num::FromPrimitive;
+
+    #[test]
+    fn modint64_works() {
+        type Mint = ModInt64<{ (1 << 61) - 1 }>;
+
+        let a = Mint::new(1 << 60);
+        let b = Mint::new(3);
+
+        assert_eq!(a * Mint::new(2), Mint::one());
+        assert_eq!(a + a, Mint::one());
+        assert_eq!(b - a - a, Mint::new(2));
+        assert_eq!(-b, Mint::new((1 << 61) - 4));
+        assert_eq!(Mint::one() / Mint::new(2), a);
+        assert_eq!(b.pow((1 << 61) - 3), b.inv());
+        assert_eq!(Mint::from_i64(-1), Some(Mint::new((1 << 61) - 2)));
+
+        type Big = ModInt64<{ u64::MAX - 58 }>;
+        let c = Big::new(u64::MAX - 59);
+        assert_eq!(c + c, Big::new(u64::MAX - 60));
+        assert_eq!(c * c, Big::one());
+        assert_eq!(Big::new(7).pow(u64::MAX - 59), Big::one());
+    }
+
+    #[test]
+    fn matches_modint() {
+        const N: u32 = 998244353;
+
+        let mut x = 1_u64;
+        for _ in 0..1000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            let (a, b) = (
+                ModInt::<N>::new(x as u32),
+                ModInt::<N>::new((x >> 32) as u32),
+            );
+            let (p, q) = (
+                ModInt64::<{ N as u64 }>::new(a.val() as u64),
+                ModInt64::<{ N as u64 }>::new(b.val() as u64),
+            );
+
+            assert_eq!((p * q).val(), (a * b).val() as u64);
+            assert_eq!((p - q).val(), (a - b).val() as u64);
+            assert_eq!(p.pow(x).val(), a.pow(x).val() as u64);
+            if b.val() != 0 {
+                assert_eq!((p / q).val(), (a / b).val() as u64);
+            }
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use super::ModInt;
+use num::{One, Zero};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// 値をMontgomery表現で保持する`ModInt`. 乗算が剰余演算を含まない
+///
+/// 互いに独立な乗算を多く行う場合に速い. `N`が定数なので`ModInt`の剰余も乗算に置き換えられており,
+/// 一つずつ順に掛ける総積では差が小さい
+///
+/// # Constraints
+///
+/// * `N`は2³¹未満の奇数
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub struct MontgomeryModInt<const N: u32>(u32);
+
+impl<const N: u32> MontgomeryModInt<N> {
+    /// -`N`⁻¹ mod 2³²
+    const NI: u32 = {
+        assert!(N & 1 == 1 && N < 1 << 31);
+
+        let mut x = N;
+        let mut i = 0;
+        while i < 4 {
+            x = x.wrapping_mul(2_u32.wrapping_sub(N.wrapping_mul(x)));
+            i += 1;
+        }
+        x.wrapping_neg()
+    };
+    /// 2⁶⁴ mod `N`
+    const R2: u32 = {
+        let r = (1_u64 << 32) % N as u64;
+        (r * r % N as u64) as u32
+    };
+
+    /// `t`·2⁻³² mod `N`を返す
+    #[inline]
+    const fn reduce(t: u64) -> u32 {
+        let m = (t as u32).wrapping_mul(Self::NI);
+        let u = ((t + m as u64 * N as u64) >> 32) as u32;
+        if u >= N {
+            u - N
+        } else {
+            u
+        }
+    }
+
+    /// `t`·2⁻³² mod `N`を[0, 2`N`)の範囲で返す
+    ///
+    /// `t` < 2³²·`N`なら結果は2`N`未満になる. 2`N`未満と`N`未満の値の積はこれを満たす
+    #[inline]
+    const fn reduce_lazy(t: u64) -> u32 {
+        let m = (t as u32).wrapping_mul(Self::NI);
+        ((t + m as u64 * N as u64) >> 32) as u32
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn new(v: u32) -> Self {
+        MontgomeryModInt(Self::reduce((v % N) as u64 * Self::R2 as u64))
+    }
+
+    /// # Safety
+    ///
+    /// * `v` < `N`
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(v: u32) -> Self {
+        debug_assert!(v < N);
+
+        MontgomeryModInt(Self::reduce(v as u64 * Self::R2 as u64))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn val(&self) -> u32 {
+        Self::reduce(self.0 as u64)
+    }
+
+    #[must_use]
+    pub const fn inv(&self) -> Self {
+        let v = ModInt::<N>::new(self.val()).inv().val();
+        MontgomeryModInt(Self::reduce(v as u64 * Self::R2 as u64))
+    }
+
+    #[must_use]
+    pub const fn pow(self, mut b: u64) -> Self {
+        let mut g = self.0;
+        let mut r = if b & 1 == 0 {
+            Self::reduce(Self::R2 as u64)
+        } else {
+            self.0
+        };
+        b >>= 1;
+        while b != 0 {
+            g = Self::reduce(g as u64 * g as u64);
+            if b & 1 == 1 {
+                r = Self::reduce(r as u64 * g as u64);
+            }
+            b >>= 1;
+        }
+        MontgomeryModInt(r)
+    }
+}
+
+impl<const N: u32> From<ModInt<N>> for MontgomeryModInt<N> {
+    #[inline]
+    fn from(value: ModInt<N>) -> Self {
+        unsafe { MontgomeryModInt::new_unchecked(value.val()) }
+    }
+}
+
+impl<const N: u32> From<MontgomeryModInt<N>> for ModInt<N> {
+    #[inline]
+    fn from(value: MontgomeryModInt<N>) -> Self {
+        unsafe { ModInt::new_unchecked(value.val()) }
+    }
+}
+
+impl<const N: u32> num::FromPrimitive for MontgomeryModInt<N> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(ModInt::<N>::from_i64(n)?.into())
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(ModInt::<N>::from_u64(n)?.into())
+    }
+}
+
+impl<const N: u32> num::ToPrimitive for MontgomeryModInt<N> {
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.val() as i64)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(self.val() as u64)
+    }
+}
+
+impl<const N: u32> Zero for MontgomeryModInt<N> {
+    fn zero() -> Self {
+        MontgomeryModInt(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const N: u32> One for MontgomeryModInt<N> {
+    fn one() -> Self {
+        MontgomeryModInt::new(1)
+    }
+}
+
+impl<const N: u32> num::traits::Inv for MontgomeryModInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn inv(self) -> Self {
+        MontgomeryModInt::inv(&self)
+    }
+}
+
+impl<const N: u32> num::traits::Inv for &MontgomeryModInt<N> {
+    type Output = MontgomeryModInt<N>;
+
+    #[inline]
+    fn inv(self) -> MontgomeryModInt<N> {
+        MontgomeryModInt::inv(self)
+    }
+}
+
+impl<const N: u32> std::fmt::Display for MontgomeryModInt<N> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.val().fmt(f)
+    }
+}
+
+impl<const N: u32> std::fmt::Debug for MontgomeryModInt<N> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.val().fmt(f)
+    }
+}
+
+impl<const N: u32> From<u32> for MontgomeryModInt<N> {
+    #[inline]
+    fn from(value: u32) -> Self {
+        MontgomeryModInt::new(value)
+    }
+}
+
+impl<const N: u32> From<MontgomeryModInt<N>> for u32 {
+    #[inline]
+    fn from(value: MontgomeryModInt<N>) -> u32 {
+        value.val()
+    }
+}
+
+impl<const N: u32> Add for MontgomeryModInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let v = self.0 + rhs.0;
+        MontgomeryModInt(if v >= N { v - N } else { v })
+    }
+}
+
+impl<const N: u32> Sub for MontgomeryModInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        let (v, f) = self.0.overflowing_sub(rhs.0);
+        MontgomeryModInt(if f { v.wrapping_add(N) } else { v })
+    }
+}
+
+impl<const N: u32> Mul for MontgomeryModInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        MontgomeryModInt(Self::reduce(self.0 as u64 * rhs.0 as u64))
+    }
+}
+
+impl<const N: u32> Div for MontgomeryModInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Mul::mul(self, rhs.inv())
+    }
+}
+
+impl<const N: u32> Neg for MontgomeryModInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        if self.0 != 0 {
+            self.0 = N - self.0;
+        }
+        self
+    }
+}
+
+impl<const N: u32> Neg for &MontgomeryModInt<N> {
+    type Output = MontgomeryModInt<N>;
+
+    #[inline]
+    fn neg(self) -> MontgomeryModInt<N> {
+        -*self
+    }
+}
+
+impl<const N: u32> std::iter::Sum for MontgomeryModInt<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(MontgomeryModInt::zero(), |a, b| a + b)
+    }
+}
+
+impl<const N: u32> std::iter::Product for MontgomeryModInt<N> {
+    /// 途中の値を[0, 2`N`)に保ち, 最後にだけ`N`未満へ正規化する
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let r = iter.fold(MontgomeryModInt::<N>::one().0, |a, b| {
+            Self::reduce_lazy(a as u64 * b.0 as u64)
+        });
+        MontgomeryModInt(if r >= N { r - N } else { r })
+    }
+}
+
+impl<const N: u32> crate::algebra::CommutativeMul for MontgomeryModInt<N> {}
+
+macro_rules! ui {
+    ($($t: ident, $i: ident, $u: ident, $j: ident),*) => ($(
+        impl<const N: u32> $u for MontgomeryModInt<N> {
+            #[inline]
+            fn $j(&mut self, rhs: Self) {
+                *self = self.$i(rhs);
+            }
+        }
+
+        impl<const N: u32> $t<MontgomeryModInt<N>> for &MontgomeryModInt<N> {
+            type Output = MontgomeryModInt<N>;
+
+            #[inline]
+            fn $i(self, rhs: MontgomeryModInt<N>) -> MontgomeryModInt<N> {
+                (*self).$i(rhs)
+            }
+        }
+        impl<const N: u32> $t<&MontgomeryModInt<N>> for MontgomeryModInt<N> {
+            type Output = MontgomeryModInt<N>;
+
+            #[inline]
+            fn $i(self, rhs: &MontgomeryModInt<N>) -> MontgomeryModInt<N> {
+                self.$i(*rhs)
+            }
+        }
+        impl<const N: u32> $t<&MontgomeryModInt<N>> for &MontgomeryModInt<N> {
+            type Output = MontgomeryModInt<N>;
+
+            #[inline]
+            fn $i(self, rhs: &MontgomeryModInt<N>) -> MontgomeryModInt<N> {
+                (*self).$i(*rhs)
+            }
+        }
+    )*);
+}
+
+ui! { Add, add, AddAssign, add_assign }
+ui! { Sub, sub, SubAssign, sub_assign }
+ui! { Mul, mul, MulAssign, mul_assign }
+ui! { Div, div, DivAssign, div_assign }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::FromPrimitive;
+
+    #[test]
+    fn montgomery_modint_works() {
+        type Mint = MontgomeryModInt<998244353>;
+
+        let a = Mint::new(314159265);
+        let b = Mint::new(358979323);
+
+        assert_eq!(-a, Mint::new(684085088));
+        assert_eq!(a + b, Mint::new(673138588));
+        assert_eq!(a - b, Mint::new(953424295));
+        assert_eq!(a * b, Mint::new(525838123));
+        assert_eq!(a / b, Mint::new(465339227));
+        assert_eq!(a.val(), 314159265);
+        assert_eq!(Mint::from_i64(-1), Some(Mint::new(998244352)));
+        assert_eq!(Mint::new(3).pow(998244352), Mint::one());
+    }
+
+    #[test]
+    fn matches_modint() {
+        const N: u32 = 2147483647;
+
+        let mut x = 1_u64;
+        for _ in 0..1000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            let a = ModInt::<N>::new(x as u32);
+            let b = ModInt::<N>::new((x >> 32) as u32);
+            let (p, q) = (MontgomeryModInt::from(a), MontgomeryModInt::from(b));
+
+            assert_eq!(ModInt::from(p * q), a * b);
+            assert_eq!(ModInt::from(p + q), a + b);
+            assert_eq!(ModInt::from(p - q), a - b);
+            assert_eq!(ModInt::from(p.pow(x)), a.pow(x));
+        }
+
+        let v: Vec<_> = (1..1000).map(|i| ModInt::<N>::new(N - i)).collect();
+        assert_eq!(
+            ModInt::from(
+                v.iter()
+                    .map(|&a| MontgomeryModInt::from(a))
+                    .product::<MontgomeryModInt<N>>()
+            ),
+            v.into_iter().product::<ModInt<N>>()
+        );
+        assert_eq!(MontgomeryModInt::<1>::new(5), MontgomeryModInt::zero());
+    }
+}
@@ -0,0 +1,270 @@
+use super::algebra::{Associative, Identity};
+use std::rc::Rc;
+
+struct Node<T> {
+    v: T,
+    c: Option<[Rc<Self>; 2]>,
+}
+
+fn build<T, I: Iterator<Item = T>, F: Fn(&T, &T) -> T>(
+    l: usize,
+    r: usize,
+    it: &mut I,
+    f: &F,
+) -> Rc<Node<T>> {
+    if r - l == 1 {
+        return Rc::new(Node {
+            v: it.next().unwrap(),
+            c: None,
+        });
+    }
+    let m = (l + r) >> 1;
+    let a = build(l, m, it, f);
+    let b = build(m, r, it, f);
+    Rc::new(Node {
+        v: f(&a.v, &b.v),
+        c: Some([a, b]),
+    })
+}
+
+/// 永続セグメントツリー
+///
+/// [`set`](PersistentSegmentTree::set)は元のセグ木を変更せず, 新しいバージョンのセグ木を返す
+pub struct PersistentSegmentTree<M: Associative + Identity> {
+    r: Option<Rc<Node<M::T>>>,
+    l: usize,
+}
+
+impl<M: Associative + Identity> Clone for PersistentSegmentTree<M> {
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    fn clone(&self) -> Self {
+        PersistentSegmentTree {
+            r: self.r.clone(),
+            l: self.l,
+        }
+    }
+}
+
+impl<M: Associative + Identity, V: Into<Vec<M::T>>> From<V> for PersistentSegmentTree<M> {
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    fn from(value: V) -> Self {
+        let v: Vec<M::T> = value.into();
+        let l = v.len();
+        if l == 0 {
+            return PersistentSegmentTree { r: None, l };
+        }
+
+        PersistentSegmentTree {
+            r: Some(build(0, l, &mut v.into_iter(), &M::op)),
+            l,
+        }
+    }
+}
+
+impl<M: Associative + Identity> PersistentSegmentTree<M> {
+    /// 要素の総数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.l
+    }
+
+    /// 空のセグ木かどうか調べる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.l == 0
+    }
+
+    fn set_node(e: &Node<M::T>, l: usize, r: usize, index: usize, value: M::T) -> Node<M::T> {
+        let Some([a, b]) = &e.c else {
+            return Node { v: value, c: None };
+        };
+        let m = (l + r) >> 1;
+        let (a, b) = if index < m {
+            (Rc::new(Self::set_node(a, l, m, index, value)), b.clone())
+        } else {
+            (a.clone(), Rc::new(Self::set_node(b, m, r, index, value)))
+        };
+        Node {
+            v: M::op(&a.v, &b.v),
+            c: Some([a, b]),
+        }
+    }
+
+    /// 値を変更した新しいセグ木を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `index` < `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(log *n*)
+    #[must_use]
+    pub fn set(&self, index: usize, value: M::T) -> Self {
+        debug_assert!(index < self.len());
+
+        let r = self.r.as_ref().unwrap();
+        PersistentSegmentTree {
+            r: Some(Rc::new(Self::set_node(r, 0, self.l, index, value))),
+            l: self.l,
+        }
+    }
+
+    /// 値を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `index` < `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(log *n*)
+    #[must_use]
+    pub fn get(&self, index: usize) -> &M::T {
+        debug_assert!(index < self.len());
+
+        let mut e = self.r.as_ref().unwrap();
+        let mut l = 0;
+        let mut r = self.l;
+        while let Some([a, b]) = &e.c {
+            let m = (l + r) >> 1;
+            if index < m {
+                e = a;
+                r = m;
+            } else {
+                e = b;
+                l = m;
+            }
+        }
+        &e.v
+    }
+
+    fn fold(e: &Node<M::T>, l: usize, r: usize, s: usize, t: usize, p: &mut M::T) {
+        if s <= l && r <= t {
+            *p = M::op(p, &e.v);
+            return;
+        }
+        let Some([a, b]) = &e.c else {
+            return;
+        };
+        let m = (l + r) >> 1;
+        if s < m {
+            Self::fold(a, l, m, s, t, p);
+        }
+        if m < t {
+            Self::fold(b, m, r, s, t, p);
+        }
+    }
+
+    /// 区間での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn prod<R: std::ops::RangeBounds<usize>>(&self, range: R) -> M::T {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(s <= e && e <= self.len());
+
+        let mut p = M::e();
+        if s < e {
+            Self::fold(self.r.as_ref().unwrap(), 0, self.l, s, e, &mut p);
+        }
+        p
+    }
+
+    /// 全体での総積を返す. 空のセグ木の場合はNoneを返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn all_prod(&self) -> Option<&M::T> {
+        self.r.as_ref().map(|e| &e.v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::Add;
+    use super::*;
+
+    #[test]
+    fn persistent_segment_tree_works() {
+        let s0 = PersistentSegmentTree::<Add<i32>>::from([3, 1, 4, 1, 5, 9, 2]);
+        let s1 = s0.set(4, 100);
+        let s2 = s1.set(0, 10);
+        let s3 = s1.set(6, 0);
+
+        assert_eq!(s0.prod(..), 25);
+        assert_eq!(s1.prod(..), 120);
+        assert_eq!(s2.prod(..), 127);
+        assert_eq!(s3.prod(..), 118);
+        assert_eq!(s0.prod(2..5), 10);
+        assert_eq!(s1.prod(2..5), 105);
+        assert_eq!(*s0.get(0), 3);
+        assert_eq!(*s2.get(0), 10);
+        assert_eq!(*s3.get(0), 3);
+        assert_eq!(s3.all_prod(), Some(&118));
+    }
+
+    #[test]
+    fn count_in_rectangle() {
+        let v = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9];
+
+        let mut h = vec![PersistentSegmentTree::<Add<i32>>::from([0; 10])];
+        for &x in v.iter() {
+            let t = h.last().unwrap();
+            h.push(t.set(x, t.get(x) + 1));
+        }
+
+        for l in 0..v.len() {
+            for r in l..=v.len() {
+                for a in 0..10 {
+                    for b in a..=10 {
+                        let c = v[l..r].iter().filter(|&&x| a <= x && x < b).count();
+                        assert_eq!((h[r].prod(a..b) - h[l].prod(a..b)) as usize, c);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn case_empty() {
+        let seg = PersistentSegmentTree::<Add<i32>>::from([]);
+
+        assert_eq!(seg.len(), 0);
+        assert_eq!(seg.prod(..), 0);
+        assert_eq!(seg.all_prod(), None);
+    }
+}
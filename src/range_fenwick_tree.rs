@@ -0,0 +1,177 @@
+use super::algebra::{pow, Associative, Commutative, Divisibility, Inverse};
+use super::fenwick_tree::FenwickTree;
+
+/// 区間加算, 区間和取得ができるフェニック木
+pub struct RangeFenwickTree<M: Associative + Commutative + Inverse> {
+    a: FenwickTree<M>,
+    b: FenwickTree<M>,
+}
+
+impl<M: Associative + Commutative + Inverse, V: Into<Vec<M::T>>> From<V> for RangeFenwickTree<M> {
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    fn from(value: V) -> Self {
+        let v: Vec<M::T> = value.into();
+        let mut b = Vec::with_capacity(v.len() + 1);
+        for x in v.iter() {
+            b.push(M::rev(x));
+        }
+        b.push(M::e());
+
+        RangeFenwickTree {
+            a: FenwickTree::new(v.len() + 1),
+            b: FenwickTree::from(b),
+        }
+    }
+}
+
+impl<M: Associative + Commutative + Inverse> RangeFenwickTree<M> {
+    /// 全ての値が`e`である長さ`n`のフェニック木を生成する
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        RangeFenwickTree {
+            a: FenwickTree::new(n + 1),
+            b: FenwickTree::new(n + 1),
+        }
+    }
+
+    /// 要素の総数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.a.len() - 1
+    }
+
+    /// 空のフェニック木かどうか調べる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 区間の各要素`a`を`op(a, x)`で置き換える
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    pub fn add<R: std::ops::RangeBounds<usize>>(&mut self, range: R, x: M::T) {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(s <= e && e <= self.len());
+
+        let y = M::rev(&x);
+        self.b.add(s, pow::<M>(&x, s as u64));
+        self.b.add(e, pow::<M>(&y, e as u64));
+        self.a.add(s, x);
+        self.a.add(e, y);
+    }
+
+    /// 区間`0..r`での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn prefix(&self, r: usize) -> M::T {
+        debug_assert!(r <= self.len());
+
+        M::op(
+            &pow::<M>(&self.a.prefix(r), r as u64),
+            &M::rev(&self.b.prefix(r)),
+        )
+    }
+
+    /// 区間での総積を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn sum<R: std::ops::RangeBounds<usize>>(&self, range: R) -> M::T
+    where
+        M: Divisibility,
+    {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(s <= e && e <= self.len());
+
+        M::div(&self.prefix(e), &self.prefix(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::Add;
+    use super::*;
+    use crate::modint::ModInt;
+
+    #[test]
+    fn range_fenwick_tree_works() {
+        let mut v = vec![3_i64, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+        let mut ft = RangeFenwickTree::<Add<i64>>::from(v.clone());
+
+        for (l, r, x) in [(2, 7, 5), (0, 10, -3), (4, 5, 100), (9, 10, 7), (3, 3, 8)] {
+            ft.add(l..r, x);
+            v[l..r].iter_mut().for_each(|a| *a += x);
+
+            for l in 0..=v.len() {
+                for r in l..=v.len() {
+                    assert_eq!(ft.sum(l..r), v[l..r].iter().sum());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn modint() {
+        type Mint = ModInt<998244353>;
+
+        let mut ft = RangeFenwickTree::<Add<Mint>>::new(6);
+        ft.add(1..4, Mint::new(499122177));
+        ft.add(2.., Mint::new(2));
+
+        assert_eq!(ft.sum(..), Mint::new(3) / Mint::new(2) + Mint::new(8));
+        assert_eq!(ft.sum(2..=2), Mint::new(499122179));
+    }
+}
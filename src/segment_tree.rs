@@ -1,4 +1,4 @@
-use super::algebra::{Associative, Identity};
+use super::algebra::{Associative, Identity, Opt};
 
 /// セグメントツリー
 #[derive(Clone)]
@@ -32,6 +32,35 @@ impl<M: Associative + Identity, V: Into<Vec<M::T>>> From<V> for SegmentTree<M> {
     }
 }
 
+impl<M: Associative> SegmentTree<Opt<M>>
+where
+    M::T: Clone,
+{
+    /// 半群の値の列から, 各値を`Some`で包んだセグ木を生成する
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    #[must_use]
+    pub fn from_values<V: Into<Vec<M::T>>>(value: V) -> Self {
+        let v: Vec<M::T> = value.into();
+        Self::from(v.into_iter().map(Some).collect::<Vec<_>>())
+    }
+}
+
+impl<T, M: Associative + Identity<T = (T, usize)>> SegmentTree<M> {
+    /// 値の列から, 各値を添字と組にしたセグ木を生成する. `MinIndex`, `MaxIndex`などに使う
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    #[must_use]
+    pub fn from_values_indexed<V: Into<Vec<T>>>(value: V) -> Self {
+        let v: Vec<T> = value.into();
+        Self::from(v.into_iter().zip(0..).collect::<Vec<_>>())
+    }
+}
+
 impl<M: Associative + Identity> std::ops::Deref for SegmentTree<M> {
     type Target = [M::T];
 
@@ -148,6 +177,102 @@ impl<M: Associative + Identity> SegmentTree<M> {
         M::op(&l, &r)
     }
 
+    /// `f(prod(l..r))`が`true`となる最大の`r`を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `n`
+    /// * `f(e)` = `true`
+    /// * `f`は単調
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn max_right<F: Fn(&M::T) -> bool>(&self, l: usize, f: F) -> usize {
+        debug_assert!(l <= self.len());
+        debug_assert!(f(&M::e()));
+
+        if l == self.len() {
+            return l;
+        }
+
+        let s = self.d.len();
+        let v = s + 1;
+        let mut i = l;
+        let mut p = M::e();
+        loop {
+            while i & 1 == 0 && i + 1 != s {
+                i = (i | v) >> 1;
+            }
+            let t = M::op(&p, &self.d[i]);
+            if !f(&t) {
+                while i >= v >> 1 {
+                    i = (i << 1) & s;
+                    let t = M::op(&p, &self.d[i]);
+                    if f(&t) {
+                        p = t;
+                        i += 1;
+                    }
+                }
+                return i;
+            }
+            if (s - i).is_power_of_two() {
+                return self.len();
+            }
+            p = t;
+            i += 1;
+        }
+    }
+
+    /// `f(prod(l..r))`が`true`となる最小の`l`を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `r` <= `n`
+    /// * `f(e)` = `true`
+    /// * `f`は単調
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn min_left<F: Fn(&M::T) -> bool>(&self, r: usize, f: F) -> usize {
+        debug_assert!(r <= self.len());
+        debug_assert!(f(&M::e()));
+
+        if r == 0 {
+            return 0;
+        }
+
+        let s = self.d.len();
+        let v = s + 1;
+        let mut i = r;
+        let mut p = M::e();
+        loop {
+            i -= 1;
+            while i & 1 == 1 {
+                i = (i | v) >> 1;
+            }
+            let t = M::op(&self.d[i], &p);
+            if !f(&t) {
+                while i >= v >> 1 {
+                    i = ((i << 1) & s) | 1;
+                    let t = M::op(&self.d[i], &p);
+                    if f(&t) {
+                        p = t;
+                        i -= 1;
+                    }
+                }
+                return i + 1;
+            }
+            if (v - i).is_power_of_two() {
+                return 0;
+            }
+            p = t;
+        }
+    }
+
     /// 全体での総積を返す. 空のセグ木の場合はNoneを返す
     ///
     /// # Complexity
@@ -162,7 +287,7 @@ impl<M: Associative + Identity> SegmentTree<M> {
 
 #[cfg(test)]
 mod tests {
-    use super::super::algebra::Add;
+    use super::super::algebra::{Add, Magma};
     use super::*;
 
     #[test]
@@ -186,5 +311,51 @@ mod tests {
         assert_eq!(seg.len(), 0);
         assert_eq!(seg.prod(..), 0);
         assert_eq!(seg.all_prod(), None);
+        assert_eq!(seg.max_right(0, |_| true), 0);
+        assert_eq!(seg.min_left(0, |_| true), 0);
+    }
+
+    #[test]
+    fn binary_search_works() {
+        let v = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+        let seg = SegmentTree::<Add<i32>>::from(v);
+
+        for l in 0..=v.len() {
+            for x in 0..50 {
+                let r = seg.max_right(l, |&s| s <= x);
+                assert!(seg.prod(l..r) <= x);
+                assert!(r == v.len() || seg.prod(l..=r) > x);
+            }
+        }
+        for r in 0..=v.len() {
+            for x in 0..50 {
+                let l = seg.min_left(r, |&s| s <= x);
+                assert!(seg.prod(l..r) <= x);
+                assert!(l == 0 || seg.prod((l - 1)..r) > x);
+            }
+        }
+    }
+
+    #[test]
+    fn semigroup() {
+        struct First;
+        impl Magma for First {
+            type T = String;
+
+            fn op(a: &String, _: &String) -> String {
+                a.clone()
+            }
+        }
+        impl Associative for First {}
+
+        let mut seg =
+            SegmentTree::<Opt<First>>::from_values(["pi", "e", "phi", "gamma"].map(String::from));
+        assert_eq!(seg.prod(1..), Some("e".to_string()));
+        assert_eq!(seg.prod(2..2), None);
+
+        seg.set(1, None);
+
+        assert_eq!(seg.prod(1..), Some("phi".to_string()));
+        assert_eq!(seg.all_prod(), Some(&Some("pi".to_string())));
     }
 }
@@ -0,0 +1,473 @@
+use num::traits::{WrappingAdd, WrappingMul, WrappingSub};
+use num::{PrimInt, Signed};
+
+#[derive(Clone, Copy)]
+struct Node<T> {
+    sum: T,
+    max: T,
+    /// 2番目に大きい値. 要素の値が全て等しい場合は`None`
+    max2: Option<T>,
+    maxc: usize,
+    min: T,
+    /// 2番目に小さい値. 要素の値が全て等しい場合は`None`
+    min2: Option<T>,
+    minc: usize,
+    len: usize,
+    add: T,
+}
+
+/// Segment Tree Beats
+///
+/// 区間chmin, 区間chmax, 区間加算と, 区間の総和, 最小値, 最大値の取得ができる
+#[derive(Clone)]
+pub struct SegmentTreeBeats<T> {
+    d: Box<[Node<T>]>,
+    s: usize,
+    l: usize,
+}
+
+impl<T, V: Into<Vec<T>>> From<V> for SegmentTreeBeats<T>
+where
+    T: PrimInt + Signed + WrappingAdd + WrappingSub + WrappingMul,
+{
+    /// # Complexity
+    ///
+    /// * *Θ*(*n*)
+    fn from(value: V) -> Self {
+        let v: Vec<T> = value.into();
+        let l = v.len();
+        let s = l.next_power_of_two();
+
+        let mut d = vec![
+            Node {
+                sum: T::zero(),
+                max: T::min_value(),
+                max2: None,
+                maxc: 0,
+                min: T::max_value(),
+                min2: None,
+                minc: 0,
+                len: 0,
+                add: T::zero(),
+            };
+            s << 1
+        ];
+        for (i, x) in v.into_iter().enumerate() {
+            d[s + i] = Node {
+                sum: x,
+                max: x,
+                max2: None,
+                maxc: 1,
+                min: x,
+                min2: None,
+                minc: 1,
+                len: 1,
+                add: T::zero(),
+            };
+        }
+
+        let mut r = SegmentTreeBeats { d: d.into(), s, l };
+        for i in (1..s).rev() {
+            r.update(i);
+        }
+        r
+    }
+}
+
+impl<T> SegmentTreeBeats<T>
+where
+    T: PrimInt + Signed + WrappingAdd + WrappingSub + WrappingMul,
+{
+    /// 要素の総数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.l
+    }
+
+    /// 空のセグ木かどうか調べる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.l == 0
+    }
+
+    /// `x`·`c`を`T`の幅で折り返して計算する
+    ///
+    /// 和は折り返し演算で管理するので, 途中の値が`T`に収まらなくても真の値が収まれば正しい
+    #[inline]
+    fn mul(x: T, c: usize) -> T {
+        if let Some(c) = T::from(c) {
+            return x.wrapping_mul(&c);
+        }
+        let mut r = T::zero();
+        for i in (0..(usize::BITS - c.leading_zeros())).rev() {
+            r = r.wrapping_add(&r);
+            if (c >> i) & 1 == 1 {
+                r = r.wrapping_add(&x);
+            }
+        }
+        r
+    }
+
+    fn update(&mut self, k: usize) {
+        let a = self.d[k << 1];
+        let b = self.d[(k << 1) | 1];
+        if b.len == 0 {
+            self.d[k] = Node {
+                add: T::zero(),
+                ..a
+            };
+            return;
+        }
+        let e = &mut self.d[k];
+
+        e.sum = a.sum.wrapping_add(&b.sum);
+        e.len = a.len + b.len;
+
+        // `Option`の順序では`None`が最小なので, `max`はそのまま使える
+        if a.max > b.max {
+            (e.max, e.max2, e.maxc) = (a.max, a.max2.max(Some(b.max)), a.maxc);
+        } else if a.max < b.max {
+            (e.max, e.max2, e.maxc) = (b.max, b.max2.max(Some(a.max)), b.maxc);
+        } else {
+            (e.max, e.max2, e.maxc) = (a.max, a.max2.max(b.max2), a.maxc + b.maxc);
+        }
+
+        let min2 = |x: Option<T>, y: Option<T>| match (x, y) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (x, None) => x,
+            (None, y) => y,
+        };
+        if a.min < b.min {
+            (e.min, e.min2, e.minc) = (a.min, min2(a.min2, Some(b.min)), a.minc);
+        } else if a.min > b.min {
+            (e.min, e.min2, e.minc) = (b.min, min2(b.min2, Some(a.min)), b.minc);
+        } else {
+            (e.min, e.min2, e.minc) = (a.min, min2(a.min2, b.min2), a.minc + b.minc);
+        }
+    }
+
+    fn apply_add(&mut self, k: usize, x: T) {
+        let e = &mut self.d[k];
+        e.sum = e.sum.wrapping_add(&Self::mul(x, e.len));
+        e.max = e.max.wrapping_add(&x);
+        e.max2 = e.max2.map(|y| y.wrapping_add(&x));
+        e.min = e.min.wrapping_add(&x);
+        e.min2 = e.min2.map(|y| y.wrapping_add(&x));
+        e.add = e.add.wrapping_add(&x);
+    }
+
+    fn apply_chmin(&mut self, k: usize, x: T) {
+        let e = &mut self.d[k];
+        e.sum = e
+            .sum
+            .wrapping_add(&Self::mul(x.wrapping_sub(&e.max), e.maxc));
+        if e.max == e.min {
+            e.min = x;
+        } else if Some(e.max) == e.min2 {
+            e.min2 = Some(x);
+        }
+        e.max = x;
+    }
+
+    fn apply_chmax(&mut self, k: usize, x: T) {
+        let e = &mut self.d[k];
+        e.sum = e
+            .sum
+            .wrapping_add(&Self::mul(x.wrapping_sub(&e.min), e.minc));
+        if e.min == e.max {
+            e.max = x;
+        } else if Some(e.min) == e.max2 {
+            e.max2 = Some(x);
+        }
+        e.min = x;
+    }
+
+    fn push(&mut self, k: usize) {
+        let e = self.d[k];
+        for c in [k << 1, (k << 1) | 1] {
+            if e.add != T::zero() {
+                self.apply_add(c, e.add);
+            }
+            if e.max < self.d[c].max {
+                self.apply_chmin(c, e.max);
+            }
+            if e.min > self.d[c].min {
+                self.apply_chmax(c, e.min);
+            }
+        }
+        self.d[k].add = T::zero();
+    }
+
+    #[inline]
+    fn bounds<R: std::ops::RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(s <= e && e <= self.len());
+
+        (s, e)
+    }
+
+    fn chmin_rec(&mut self, k: usize, l: usize, r: usize, s: usize, e: usize, x: T) {
+        if e <= l || r <= s || self.d[k].max <= x {
+            return;
+        }
+        if s <= l && r <= e && self.d[k].max2 < Some(x) {
+            self.apply_chmin(k, x);
+            return;
+        }
+        self.push(k);
+        let m = (l + r) >> 1;
+        self.chmin_rec(k << 1, l, m, s, e, x);
+        self.chmin_rec((k << 1) | 1, m, r, s, e, x);
+        self.update(k);
+    }
+
+    fn chmax_rec(&mut self, k: usize, l: usize, r: usize, s: usize, e: usize, x: T) {
+        if e <= l || r <= s || self.d[k].min >= x {
+            return;
+        }
+        if s <= l && r <= e && self.d[k].min2.is_none_or(|y| y > x) {
+            self.apply_chmax(k, x);
+            return;
+        }
+        self.push(k);
+        let m = (l + r) >> 1;
+        self.chmax_rec(k << 1, l, m, s, e, x);
+        self.chmax_rec((k << 1) | 1, m, r, s, e, x);
+        self.update(k);
+    }
+
+    fn add_rec(&mut self, k: usize, l: usize, r: usize, s: usize, e: usize, x: T) {
+        if e <= l || r <= s {
+            return;
+        }
+        if s <= l && r <= e {
+            self.apply_add(k, x);
+            return;
+        }
+        self.push(k);
+        let m = (l + r) >> 1;
+        self.add_rec(k << 1, l, m, s, e, x);
+        self.add_rec((k << 1) | 1, m, r, s, e, x);
+        self.update(k);
+    }
+
+    fn fold<F: FnMut(&Node<T>)>(
+        &mut self,
+        k: usize,
+        l: usize,
+        r: usize,
+        s: usize,
+        e: usize,
+        f: &mut F,
+    ) {
+        if e <= l || r <= s {
+            return;
+        }
+        if s <= l && r <= e {
+            f(&self.d[k]);
+            return;
+        }
+        self.push(k);
+        let m = (l + r) >> 1;
+        self.fold(k << 1, l, m, s, e, f);
+        self.fold((k << 1) | 1, m, r, s, e, f);
+    }
+
+    /// 区間の各要素`a`を`min(a, x)`で置き換える
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * amortized *O*(log² *n*)
+    pub fn chmin<R: std::ops::RangeBounds<usize>>(&mut self, range: R, x: T) {
+        let (s, e) = self.bounds(range);
+        self.chmin_rec(1, 0, self.s, s, e, x);
+    }
+
+    /// 区間の各要素`a`を`max(a, x)`で置き換える
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * amortized *O*(log² *n*)
+    pub fn chmax<R: std::ops::RangeBounds<usize>>(&mut self, range: R, x: T) {
+        let (s, e) = self.bounds(range);
+        self.chmax_rec(1, 0, self.s, s, e, x);
+    }
+
+    /// 区間の各要素に`x`を加算する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * amortized *O*(log² *n*)
+    pub fn add<R: std::ops::RangeBounds<usize>>(&mut self, range: R, x: T) {
+        let (s, e) = self.bounds(range);
+        self.add_rec(1, 0, self.s, s, e, x);
+    }
+
+    /// 区間の総和を取得する
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn sum<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> T {
+        let (s, e) = self.bounds(range);
+        let mut p = T::zero();
+        self.fold(1, 0, self.s, s, e, &mut |e| p = p.wrapping_add(&e.sum));
+        p
+    }
+
+    /// 区間の最小値を取得する. 空の区間の場合は`T::max_value()`を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn min<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> T {
+        let (s, e) = self.bounds(range);
+        let mut p = T::max_value();
+        self.fold(1, 0, self.s, s, e, &mut |e| p = p.min(e.min));
+        p
+    }
+
+    /// 区間の最大値を取得する. 空の区間の場合は`T::min_value()`を返す
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *O*(log *n*)
+    #[must_use]
+    pub fn max<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> T {
+        let (s, e) = self.bounds(range);
+        let mut p = T::min_value();
+        self.fold(1, 0, self.s, s, e, &mut |e| p = p.max(e.max));
+        p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_tree_beats_works() {
+        let mut v = vec![
+            3_i64, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 3, 8, 4, 6,
+        ];
+        let mut seg = SegmentTreeBeats::from(v.clone());
+
+        let mut x = 88172645463325252_u64;
+        for _ in 0..2000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            let t = x % 6;
+            let l = (x >> 8) as usize % v.len();
+            let r = l + 1 + (x >> 24) as usize % (v.len() - l);
+            let y = (x >> 40) as i64 % 21 - 10;
+            match t {
+                0 => {
+                    seg.chmin(l..r, y);
+                    v[l..r].iter_mut().for_each(|a| *a = (*a).min(y));
+                }
+                1 => {
+                    seg.chmax(l..r, y);
+                    v[l..r].iter_mut().for_each(|a| *a = (*a).max(y));
+                }
+                2 => {
+                    seg.add(l..r, y);
+                    v[l..r].iter_mut().for_each(|a| *a += y);
+                }
+                3 => assert_eq!(seg.sum(l..r), v[l..r].iter().sum()),
+                4 => assert_eq!(seg.min(l..r), *v[l..r].iter().min().unwrap()),
+                _ => assert_eq!(seg.max(l..r), *v[l..r].iter().max().unwrap()),
+            }
+        }
+    }
+
+    #[test]
+    fn small_types() {
+        let mut seg = SegmentTreeBeats::<i8>::from([5, -3, 7, 0]);
+        seg.chmin(.., 4);
+        seg.chmax(1..3, -1);
+        seg.add(3.., -120);
+
+        assert_eq!(seg.sum(..), -113);
+        assert_eq!(seg.min(..), -120);
+        assert_eq!(seg.max(1..), 4);
+        assert_eq!(seg.min(2..2), i8::MAX);
+
+        let mut seg = SegmentTreeBeats::<i8>::from(vec![-1; 200]);
+        seg.add(.., 1);
+        assert_eq!(seg.sum(..), 0);
+        seg.add(..100, 1);
+        seg.chmax(50.., 0);
+        assert_eq!(seg.sum(..100), 100);
+        assert_eq!(seg.sum(40..160), 60);
+        seg.chmin(..128, -1);
+        seg.add(..128, 2);
+        seg.add(127.., -1);
+        assert_eq!(seg.sum(..127), 127);
+        assert_eq!(seg.sum(..128), 127);
+        assert_eq!(seg.max(..), 1);
+        assert_eq!(seg.min(..), -1);
+
+        let mut seg = SegmentTreeBeats::<i8>::from([-128; 4]);
+        seg.add(.., 100);
+        seg.add(.., 100);
+        seg.add(.., 55);
+        assert_eq!(seg.sum(..1), 127);
+        assert_eq!(seg.min(1..), 127);
+
+        let mut seg = SegmentTreeBeats::<i8>::from([-128, 10]);
+        seg.add(.., 1);
+        seg.chmin(.., -127);
+        seg.add(.., 5);
+        seg.chmin(.., -125);
+        assert_eq!(seg.sum(..), -250_i16 as i8);
+        assert_eq!(seg.max(..), -125);
+        seg.chmax(.., 127);
+        assert_eq!(seg.sum(..), -2);
+    }
+}
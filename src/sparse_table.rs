@@ -0,0 +1,124 @@
+use super::algebra::{Associative, Idempotent};
+
+/// スパーステーブル
+pub struct SparseTable<M: Associative + Idempotent> {
+    d: Box<[Box<[M::T]>]>,
+}
+
+impl<M: Associative + Idempotent, V: Into<Vec<M::T>>> From<V> for SparseTable<M> {
+    /// # Complexity
+    ///
+    /// * *Θ*(*n* log *n*)
+    fn from(value: V) -> Self {
+        let v: Vec<M::T> = value.into();
+        let l = v.len();
+
+        let mut d = vec![v.into_boxed_slice()];
+        let mut k = 1;
+        while k << 1 <= l {
+            let p = d.last().unwrap();
+            let mut e = Vec::with_capacity(l + 1 - (k << 1));
+            for i in 0..=(l - (k << 1)) {
+                e.push(M::op(&p[i], &p[i + k]));
+            }
+            d.push(e.into());
+            k <<= 1;
+        }
+
+        SparseTable { d: d.into() }
+    }
+}
+
+impl<M: Associative + Idempotent> std::ops::Deref for SparseTable<M> {
+    type Target = [M::T];
+
+    fn deref(&self) -> &[M::T] {
+        &self.d[0]
+    }
+}
+
+impl<M: Associative + Idempotent> SparseTable<M> {
+    /// 要素の総数を返す
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.d[0].len()
+    }
+
+    /// 空のテーブルかどうか調べる
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.d[0].is_empty()
+    }
+
+    /// 区間での総積を取得する. 空の区間の場合はNoneを返す
+    ///
+    /// # Constraints
+    ///
+    /// * `l` <= `r` <= `n`
+    ///
+    /// # Complexity
+    ///
+    /// * *Θ*(1)
+    #[must_use]
+    pub fn prod<R: std::ops::RangeBounds<usize>>(&self, range: R) -> Option<M::T> {
+        let s = match range.start_bound() {
+            std::ops::Bound::Included(&i) => i,
+            std::ops::Bound::Excluded(&i) => i + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let e = match range.end_bound() {
+            std::ops::Bound::Included(&i) => i + 1,
+            std::ops::Bound::Excluded(&i) => i,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+        debug_assert!(s <= e && e <= self.len());
+
+        if s == e {
+            return None;
+        }
+
+        let k = (usize::BITS - 1 - (e - s).leading_zeros()) as usize;
+        Some(M::op(&self.d[k][s], &self.d[k][e - (1 << k)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::algebra::{Max, Min};
+    use super::*;
+
+    #[test]
+    fn sparse_table_works() {
+        let v = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 3, 8, 4];
+        let min = SparseTable::<Min<i32>>::from(v);
+        let max = SparseTable::<Max<i32>>::from(v);
+
+        for l in 0..v.len() {
+            for r in (l + 1)..=v.len() {
+                assert_eq!(min.prod(l..r), v[l..r].iter().min().copied());
+                assert_eq!(max.prod(l..r), v[l..r].iter().max().copied());
+            }
+        }
+        assert_eq!(min.prod(..), Some(1));
+        assert_eq!(max.prod(4..=5), Some(9));
+        assert_eq!(min.prod(3..3), None);
+    }
+
+    #[test]
+    fn case_empty() {
+        let st = SparseTable::<Min<i32>>::from([]);
+
+        assert_eq!(st.len(), 0);
+        assert_eq!(st.prod(..), None);
+    }
+}